@@ -157,7 +157,13 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::XBountyProxy)
-            .fund(repo_owner, repo_url, issue_id)
+            .fund(
+                repo_owner,
+                repo_url,
+                issue_id,
+                OptionalValue::<u64>::None,
+                OptionalValue::<u64>::None,
+            )
             .egld(egld_amount)
             .returns(ReturnsResultUnmanaged)
             .run()
@@ -168,7 +174,6 @@ impl ContractInteract {
         let repo_owner = ManagedBuffer::from("multiversx");
         let repo_url = ManagedBuffer::from("mx-contracts-rs");
         let issue_id = 133u64;
-        let github_id = ManagedBuffer::from("costincarabas");
 
         self.interactor
             .tx()
@@ -176,7 +181,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::XBountyProxy)
-            .register(repo_owner, repo_url, issue_id, github_id)
+            .register(repo_owner, repo_url, issue_id)
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -186,7 +191,6 @@ impl ContractInteract {
         let repo_owner = ManagedBuffer::from("multiversx");
         let repo_url = ManagedBuffer::from("mx-contracts-rs");
         let issue_id = 133u64;
-        let github_id = ManagedBuffer::from("costincarabas");
 
         self.interactor
             .tx()
@@ -194,13 +198,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(proxy::XBountyProxy)
-            .release_bounty(
-                repo_owner,
-                repo_url,
-                issue_id,
-                self.solver_address.clone(),
-                github_id,
-            )
+            .release_bounty(repo_owner, repo_url, issue_id, self.solver_address.clone())
             .returns(ReturnsResultUnmanaged)
             .run()
             .await;
@@ -6,10 +6,73 @@ pub trait EventsModule {
         #[indexed] repo_owner: ManagedBuffer,
         #[indexed] repo_url: ManagedBuffer,
         #[indexed] issue_id: u64,
+        #[indexed] token: EgldOrEsdtTokenIdentifier,
         #[indexed] amount: BigUint,
         #[indexed] proposer: ManagedAddress,
     );
 
+    #[event("createTreasury")]
+    fn create_treasury_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] admin: ManagedAddress,
+    );
+
+    #[event("transferTreasuryAdmin")]
+    fn transfer_treasury_admin_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] previous_admin: ManagedAddress,
+        #[indexed] new_admin: ManagedAddress,
+    );
+
+    #[event("treasuryDeposit")]
+    fn treasury_deposit_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] token: EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: BigUint,
+        #[indexed] depositor: ManagedAddress,
+    );
+
+    #[event("treasuryWithdraw")]
+    fn treasury_withdraw_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] token: EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: BigUint,
+        #[indexed] admin: ManagedAddress,
+    );
+
+    #[event("topUp")]
+    fn top_up_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] token: EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: BigUint,
+        #[indexed] backer: ManagedAddress,
+    );
+
+    #[event("linkGithub")]
+    fn link_github_event(
+        &self,
+        #[indexed] solver_addr: ManagedAddress,
+        #[indexed] solver_github: ManagedBuffer,
+    );
+
+    #[event("unlinkGithub")]
+    fn unlink_github_event(
+        &self,
+        #[indexed] solver_addr: ManagedAddress,
+        #[indexed] solver_github: ManagedBuffer,
+    );
+
     #[event("claim")]
     fn claim_event(
         &self,
@@ -20,6 +83,95 @@ pub trait EventsModule {
         #[indexed] solver_github: ManagedBuffer,
     );
 
+    #[event("withdrawRegistration")]
+    fn withdraw_registration_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] solver_addr: ManagedAddress,
+        #[indexed] solver_github: ManagedBuffer,
+    );
+
+    #[event("rejectSolver")]
+    fn reject_solver_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] solver_addr: ManagedAddress,
+        #[indexed] solver_github: ManagedBuffer,
+        #[indexed] rejected_by: ManagedAddress,
+        #[indexed] reason_hash: ManagedBuffer,
+    );
+
+    #[event("expireRegistration")]
+    fn expire_registration_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] solver_addr: ManagedAddress,
+        #[indexed] solver_github: ManagedBuffer,
+    );
+
+    #[event("addDelegate")]
+    fn add_delegate_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] proposer: ManagedAddress,
+        #[indexed] delegate: ManagedAddress,
+    );
+
+    #[event("removeDelegate")]
+    fn remove_delegate_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] proposer: ManagedAddress,
+        #[indexed] delegate: ManagedAddress,
+    );
+
+    #[event("addRepoDelegate")]
+    fn add_repo_delegate_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] proposer: ManagedAddress,
+        #[indexed] delegate: ManagedAddress,
+    );
+
+    #[event("removeRepoDelegate")]
+    fn remove_repo_delegate_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] proposer: ManagedAddress,
+        #[indexed] delegate: ManagedAddress,
+    );
+
+    #[event("approveRelease")]
+    fn approve_release_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] approver: ManagedAddress,
+        #[indexed] solver_addr: ManagedAddress,
+    );
+
+    #[event("revokeApproval")]
+    fn revoke_approval_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] approver: ManagedAddress,
+    );
+
     #[event("complete")]
     fn complete_event(
         &self,
@@ -28,6 +180,118 @@ pub trait EventsModule {
         #[indexed] issue_id: u64,
         #[indexed] solver_addr: ManagedAddress,
         #[indexed] solver_github: ManagedBuffer,
+        #[indexed] token: EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: BigUint,
+        #[indexed] fee: BigUint,
+    );
+
+    #[event("milestone")]
+    fn milestone_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] milestone_index: usize,
+        #[indexed] solver_addr: ManagedAddress,
+        #[indexed] token: EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: BigUint,
+        #[indexed] fee: BigUint,
+    );
+
+    #[event("oracleRelease")]
+    fn oracle_release_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] pr_number: u64,
+        #[indexed] oracle: ManagedAddress,
+    );
+
+    #[event("attestationRelease")]
+    fn attestation_release_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] pr_number: u64,
+        #[indexed] nonce: u64,
+    );
+
+    #[event("submitCompletion")]
+    fn submit_completion_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] solver_addr: ManagedAddress,
+        #[indexed] pr_reference: ManagedBuffer,
+    );
+
+    #[event("finalize")]
+    fn finalize_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] pr_reference: ManagedBuffer,
+        #[indexed] caller: ManagedAddress,
+    );
+
+    #[event("openDispute")]
+    fn open_dispute_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] opened_by: ManagedAddress,
+        #[indexed] reason_hash: ManagedBuffer,
+    );
+
+    #[event("resolveDispute")]
+    fn resolve_dispute_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] arbiter: ManagedAddress,
+        #[indexed] solver_addr: ManagedAddress,
+        #[indexed] solver_share_bps: u64,
+        #[indexed] payout: BigUint,
+        #[indexed] refunded_amount: BigUint,
+    );
+
+    #[event("expireDispute")]
+    fn expire_dispute_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] caller: ManagedAddress,
+    );
+
+    #[event("cancel")]
+    fn cancel_event(
+        &self,
+        #[indexed] repo_owner: ManagedBuffer,
+        #[indexed] repo_url: ManagedBuffer,
+        #[indexed] issue_id: u64,
+        #[indexed] token: EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: BigUint,
+        #[indexed] proposer: ManagedAddress,
+    );
+
+    #[event("withdrawFees")]
+    fn withdraw_fees_event(
+        &self,
+        #[indexed] owner: ManagedAddress,
+        #[indexed] token: EgldOrEsdtTokenIdentifier,
         #[indexed] amount: BigUint,
     );
+
+    #[event("pause")]
+    fn pause_event(&self);
+
+    #[event("unpause")]
+    fn unpause_event(&self);
 }
@@ -0,0 +1,28 @@
+use multiversx_sc::imports::*;
+
+#[multiversx_sc::module]
+pub trait PauseModule: crate::events::EventsModule {
+    #[only_owner]
+    #[endpoint]
+    fn pause(&self) {
+        self.paused().set(true);
+
+        self.pause_event();
+    }
+
+    #[only_owner]
+    #[endpoint]
+    fn unpause(&self) {
+        self.paused().clear();
+
+        self.unpause_event();
+    }
+
+    fn require_not_paused(&self) {
+        require!(!self.paused().get(), "Contract is paused");
+    }
+
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+}
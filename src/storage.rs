@@ -8,6 +8,8 @@ use multiversx_sc::imports::*;
 pub struct Solver<M: ManagedTypeApi> {
     pub solver_addr: ManagedAddress<M>,
     pub solver_github: ManagedBuffer<M>,
+    pub deadline: u64,
+    pub stake: BigUint<M>,
 }
 
 #[type_abi]
@@ -16,19 +18,188 @@ pub enum BountyStatus {
     Funded,
     Registered,
     Completed,
+    Cancelled,
+    Disputed,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, ManagedVecItem, PartialEq,
+)]
+pub enum MilestoneStatus {
+    Pending,
+    Paid,
+    Cancelled,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, ManagedVecItem)]
+pub struct Milestone<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub description_hash: ManagedBuffer<M>,
+    pub status: MilestoneStatus,
 }
 
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
 pub struct Bounty<M: ManagedTypeApi> {
+    pub id: u64,
     pub repo_url: ManagedBuffer<M>,
     pub issue_id: u64,
     pub repo_owner: ManagedBuffer<M>,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
     pub amount: BigUint<M>,
     pub proposer: ManagedAddress<M>,
     pub solvers: ManagedVec<M, Solver<M>>,
     pub status: BountyStatus,
     pub created_at: u64,
+    pub milestones: ManagedVec<M, Milestone<M>>,
+    pub work_period: u64,
+    pub lockup: u64,
+    pub was_registered: bool,
+    pub approvers: ManagedVec<M, ManagedAddress<M>>,
+    pub approval_threshold: usize,
+    pub delegates: ManagedVec<M, ManagedAddress<M>>,
+    pub registration_stake: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> Bounty<M> {
+    pub fn is_open(&self) -> bool {
+        self.status == BountyStatus::Funded || self.status == BountyStatus::Registered
+    }
+
+    pub fn escrowed_amount(&self) -> BigUint<M> {
+        if self.is_open() || self.status == BountyStatus::Disputed {
+            &self.amount - &self.paid_amount()
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    pub fn paid_amount(&self) -> BigUint<M> {
+        let mut paid_amount = BigUint::zero();
+        for milestone in self.milestones.iter() {
+            if milestone.status == MilestoneStatus::Paid {
+                paid_amount += &milestone.amount;
+            }
+        }
+
+        paid_amount
+    }
+
+    pub fn next_pending_milestone(&self) -> Option<usize> {
+        self.milestones
+            .iter()
+            .position(|milestone| milestone.status == MilestoneStatus::Pending)
+    }
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, ManagedVecItem, PartialEq,
+)]
+pub struct BountyKey<M: ManagedTypeApi> {
+    pub repo_owner: ManagedBuffer<M>,
+    pub repo_url: ManagedBuffer<M>,
+    pub issue_id: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, ManagedVecItem)]
+pub struct TokenAmount<M: ManagedTypeApi> {
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncodeOrDefault, TopDecodeOrDefault, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct SolverStats {
+    pub registrations: u64,
+    pub completed_bounties: u64,
+    pub first_completed_at: u64,
+    pub last_completed_at: u64,
+}
+
+impl codec::EncodeDefault for SolverStats {
+    fn is_default(&self) -> bool {
+        self.registrations == 0 && self.completed_bounties == 0
+    }
+}
+
+impl codec::DecodeDefault for SolverStats {
+    fn default() -> Self {
+        Self {
+            registrations: 0,
+            completed_bounties: 0,
+            first_completed_at: 0,
+            last_completed_at: 0,
+        }
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct SolverProfile<M: ManagedTypeApi> {
+    pub solver_addr: ManagedAddress<M>,
+    pub completed_bounties: u64,
+    pub uncompleted_registrations: u64,
+    pub first_completed_at: u64,
+    pub last_completed_at: u64,
+    pub earnings: ManagedVec<M, TokenAmount<M>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, ManagedVecItem)]
+pub struct LeaderboardEntry<M: ManagedTypeApi> {
+    pub solver_addr: ManagedAddress<M>,
+    pub earned: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct IdentityProof<M: ManagedTypeApi> {
+    pub solver_addr: ManagedAddress<M>,
+    pub solver_github: ManagedBuffer<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct MergeAttestation<M: ManagedTypeApi> {
+    pub repo_owner: ManagedBuffer<M>,
+    pub repo_url: ManagedBuffer<M>,
+    pub issue_id: u64,
+    pub pr_number: u64,
+    pub solver_github: ManagedBuffer<M>,
+    pub solver_addr: ManagedAddress<M>,
+    pub nonce: u64,
+    pub expiry: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Dispute<M: ManagedTypeApi> {
+    pub opened_by: ManagedAddress<M>,
+    pub reason_hash: ManagedBuffer<M>,
+    pub opened_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct CompletionClaim<M: ManagedTypeApi> {
+    pub solver_addr: ManagedAddress<M>,
+    pub pr_reference: ManagedBuffer<M>,
+    pub submitted_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct BadgeAttributes<M: ManagedTypeApi> {
+    pub repo_owner: ManagedBuffer<M>,
+    pub repo_url: ManagedBuffer<M>,
+    pub issue_id: u64,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub completed_at: u64,
 }
 
 #[multiversx_sc::module]
@@ -42,6 +213,198 @@ pub trait StorageModule {
         issue_id: &u64,
     ) -> SingleValueMapper<Bounty<Self::Api>>;
 
+    #[view(getBountyId)]
+    #[storage_mapper("bountyId")]
+    fn bounty_id(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+        issue_id: &u64,
+    ) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("bountyKeys")]
+    fn bounty_keys(&self) -> VecMapper<BountyKey<Self::Api>>;
+
+    #[storage_mapper("openBountiesByOrg")]
+    fn open_bounties_by_org(&self, repo_owner: &ManagedBuffer) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("openBountiesByRepo")]
+    fn open_bounties_by_repo(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+    ) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("bountiesByProposer")]
+    fn bounties_by_proposer(&self, proposer: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("bountiesBySolver")]
+    fn bounties_by_solver(&self, solver_addr: &ManagedAddress) -> UnorderedSetMapper<u64>;
+
+    #[storage_mapper("earnings")]
+    fn earnings(
+        &self,
+        solver_addr: &ManagedAddress,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
+
+    #[storage_mapper("solverStats")]
+    fn solver_stats(&self, solver_addr: &ManagedAddress) -> SingleValueMapper<SolverStats>;
+
+    #[storage_mapper("rankedSolvers")]
+    fn ranked_solvers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getContributions)]
+    #[storage_mapper("contributions")]
+    fn contributions(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+        issue_id: &u64,
+    ) -> MapMapper<ManagedAddress, BigUint>;
+
+    #[storage_mapper("payees")]
+    fn payees(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+        issue_id: &u64,
+    ) -> MapMapper<ManagedAddress, BigUint>;
+
+    #[view(getRejectedSolvers)]
+    #[storage_mapper("rejectedSolvers")]
+    fn rejected_solvers(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+        issue_id: &u64,
+    ) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getRepoDelegates)]
+    #[storage_mapper("repoDelegates")]
+    fn repo_delegates(
+        &self,
+        proposer: &ManagedAddress,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+    ) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getReleaseApprovals)]
+    #[storage_mapper("releaseApprovals")]
+    fn release_approvals(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+        issue_id: &u64,
+    ) -> MapMapper<ManagedAddress, ManagedAddress>;
+
+    #[view(getTreasury)]
+    #[storage_mapper("treasury")]
+    fn treasury(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+    ) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
+
+    #[view(getTreasuryAdmin)]
+    #[storage_mapper("treasuryAdmin")]
+    fn treasury_admin(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+    ) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getTreasuryMaintainers)]
+    #[storage_mapper("treasuryMaintainers")]
+    fn treasury_maintainers(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+    ) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getMaxSolvers)]
+    #[storage_mapper("maxSolvers")]
+    fn max_solvers(&self) -> SingleValueMapper<usize>;
+
+    #[view(getMaxBackers)]
+    #[storage_mapper("maxBackers")]
+    fn max_backers(&self) -> SingleValueMapper<usize>;
+
+    #[view(getDefaultWorkPeriod)]
+    #[storage_mapper("defaultWorkPeriod")]
+    fn default_work_period(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMinLockup)]
+    #[storage_mapper("minLockup")]
+    fn min_lockup(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMaxLockup)]
+    #[storage_mapper("maxLockup")]
+    fn max_lockup(&self) -> SingleValueMapper<u64>;
+
+    #[view(getSolverGithub)]
     #[storage_mapper("solvers")]
     fn solvers(&self, solver_addr: &ManagedAddress) -> SingleValueMapper<ManagedBuffer<Self::Api>>;
+
+    #[view(getSolverAddress)]
+    #[storage_mapper("solverAddress")]
+    fn solver_address(&self, solver_github: &ManagedBuffer) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getOracles)]
+    #[storage_mapper("oracles")]
+    fn oracles(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getArbiters)]
+    #[storage_mapper("arbiters")]
+    fn arbiters(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getDispute)]
+    #[storage_mapper("disputes")]
+    fn disputes(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+        issue_id: &u64,
+    ) -> SingleValueMapper<Dispute<Self::Api>>;
+
+    #[storage_mapper("completionClaims")]
+    fn completion_claims(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+        issue_id: &u64,
+    ) -> SingleValueMapper<CompletionClaim<Self::Api>>;
+
+    #[view(getDisputeTimeout)]
+    #[storage_mapper("disputeTimeout")]
+    fn dispute_timeout(&self) -> SingleValueMapper<u64>;
+
+    #[view(getReviewPeriod)]
+    #[storage_mapper("reviewPeriod")]
+    fn review_period(&self) -> SingleValueMapper<u64>;
+
+    #[view(getAttestationSigner)]
+    #[storage_mapper("attestationSigner")]
+    fn attestation_signer(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("usedNonces")]
+    fn used_nonces(&self) -> WhitelistMapper<u64>;
+
+    #[view(getStorageVersion)]
+    #[storage_mapper("storageVersion")]
+    fn storage_version(&self) -> SingleValueMapper<u32>;
+
+    #[view(getFee)]
+    #[storage_mapper("feeBps")]
+    fn fee_bps(&self) -> SingleValueMapper<u64>;
+
+    #[view(getCollectedFees)]
+    #[storage_mapper("collectedFees")]
+    fn collected_fees(&self) -> MapMapper<EgldOrEsdtTokenIdentifier, BigUint>;
+
+    #[view(getBadgeToken)]
+    #[storage_mapper("badgeToken")]
+    fn badge_token(&self) -> NonFungibleTokenMapper;
+
+    #[storage_mapper("badgeBounty")]
+    fn badge_bounty(&self, badge_nonce: u64) -> SingleValueMapper<u64>;
 }
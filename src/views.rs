@@ -0,0 +1,282 @@
+use multiversx_sc::imports::*;
+
+use crate::storage::{
+    Bounty, BountyKey, BountyStatus, CompletionClaim, LeaderboardEntry, SolverProfile, TokenAmount,
+};
+
+#[multiversx_sc::module]
+pub trait ViewsModule: crate::storage::StorageModule {
+    #[view(getBountyCount)]
+    fn get_bounty_count(&self) -> usize {
+        self.bounty_keys().len()
+    }
+
+    #[view(getAllBounties)]
+    fn get_all_bounties(&self, from: usize, size: usize) -> MultiValueEncoded<Bounty<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for index in self.bounty_key_range(from, size) {
+            result.push(self.get_bounty_by_key(&self.bounty_keys().get(index)));
+        }
+
+        result
+    }
+
+    /// Pages over bounty ids rather than over matches, so a page never loads
+    /// more than `size` bounties and may hold fewer matches than that.
+    #[view(getBountiesByStatus)]
+    fn get_bounties_by_status(
+        &self,
+        status: BountyStatus,
+        from: usize,
+        size: usize,
+    ) -> MultiValueEncoded<Bounty<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for index in self.bounty_key_range(from, size) {
+            let bounty = self.get_bounty_by_key(&self.bounty_keys().get(index));
+            if bounty.status == status {
+                result.push(bounty);
+            }
+        }
+
+        result
+    }
+
+    #[view(getOpenBountiesByOrg)]
+    fn get_open_bounties_by_org(
+        &self,
+        repo_owner: ManagedBuffer,
+    ) -> MultiValueEncoded<Bounty<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for bounty_id in self.open_bounties_by_org(&repo_owner).iter() {
+            result.push(self.get_bounty_by_id(bounty_id));
+        }
+
+        result
+    }
+
+    #[view(getOpenBountiesByRepo)]
+    fn get_open_bounties_by_repo(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+    ) -> MultiValueEncoded<Bounty<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for bounty_id in self.open_bounties_by_repo(&repo_owner, &repo_url).iter() {
+            result.push(self.get_bounty_by_id(bounty_id));
+        }
+
+        result
+    }
+
+    #[view(getRepoEscrow)]
+    fn get_repo_escrow(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+    ) -> ManagedVec<TokenAmount<Self::Api>> {
+        let mut escrow = ManagedVec::new();
+        for bounty_id in self.open_bounties_by_repo(&repo_owner, &repo_url).iter() {
+            let bounty = self.get_bounty_by_id(bounty_id);
+            self.add_to_totals(&mut escrow, &bounty.token, &bounty.escrowed_amount());
+        }
+
+        escrow
+    }
+
+    #[view(getBountiesByProposer)]
+    fn get_bounties_by_proposer(
+        &self,
+        proposer: ManagedAddress,
+    ) -> MultiValueEncoded<Bounty<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for bounty_id in self.bounties_by_proposer(&proposer).iter() {
+            result.push(self.get_bounty_by_id(bounty_id));
+        }
+
+        result
+    }
+
+    #[view(getBountiesBySolver)]
+    fn get_bounties_by_solver(
+        &self,
+        solver_addr: ManagedAddress,
+    ) -> MultiValueEncoded<Bounty<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for bounty_id in self.bounties_by_solver(&solver_addr).iter() {
+            result.push(self.get_bounty_by_id(bounty_id));
+        }
+
+        result
+    }
+
+    #[view(getProposerEscrow)]
+    fn get_proposer_escrow(&self, proposer: ManagedAddress) -> ManagedVec<TokenAmount<Self::Api>> {
+        let mut escrow = ManagedVec::new();
+        for bounty_id in self.bounties_by_proposer(&proposer).iter() {
+            let bounty = self.get_bounty_by_id(bounty_id);
+            self.add_to_totals(&mut escrow, &bounty.token, &bounty.escrowed_amount());
+        }
+
+        escrow
+    }
+
+    #[view(getSolverEarnings)]
+    fn get_solver_earnings(
+        &self,
+        solver_addr: ManagedAddress,
+    ) -> ManagedVec<TokenAmount<Self::Api>> {
+        let mut earnings = ManagedVec::new();
+        for (token, amount) in self.earnings(&solver_addr).iter() {
+            earnings.push(TokenAmount { token, amount });
+        }
+
+        earnings
+    }
+
+    #[view(getSolverStats)]
+    fn get_solver_stats(&self, solver_addr: ManagedAddress) -> SolverProfile<Self::Api> {
+        let stats = self.solver_stats(&solver_addr).get();
+        let earnings = self.get_solver_earnings(solver_addr.clone());
+
+        // Every completion is backed by a registration on that bounty
+        let uncompleted_registrations =
+            match stats.registrations.checked_sub(stats.completed_bounties) {
+                Some(uncompleted_registrations) => uncompleted_registrations,
+                None => sc_panic!("Solver has more completions than registrations"),
+            };
+
+        SolverProfile {
+            solver_addr,
+            completed_bounties: stats.completed_bounties,
+            uncompleted_registrations,
+            first_completed_at: stats.first_completed_at,
+            last_completed_at: stats.last_completed_at,
+            earnings,
+        }
+    }
+
+    #[view(getLeaderboard)]
+    fn get_leaderboard(
+        &self,
+        token: EgldOrEsdtTokenIdentifier,
+        size: usize,
+    ) -> ManagedVec<LeaderboardEntry<Self::Api>> {
+        // Keep the top `size` solvers sorted by earnings, highest first
+        let mut leaderboard = ManagedVec::<Self::Api, LeaderboardEntry<Self::Api>>::new();
+        for solver_addr in self.ranked_solvers().iter() {
+            let earned = self.earnings(&solver_addr).get(&token).unwrap_or_default();
+            if earned == 0 {
+                continue;
+            }
+
+            let position = leaderboard
+                .iter()
+                .position(|entry| entry.earned < earned)
+                .unwrap_or(leaderboard.len());
+            if position >= size {
+                continue;
+            }
+
+            let mut updated = leaderboard.slice(0, position).unwrap_or_default();
+            updated.push(LeaderboardEntry {
+                solver_addr,
+                earned,
+            });
+            let end = core::cmp::min(leaderboard.len(), size - 1);
+            if let Some(tail) = leaderboard.slice(position, end) {
+                updated.append_vec(tail);
+            }
+            leaderboard = updated;
+        }
+
+        leaderboard
+    }
+
+    #[view(getBadgeBounty)]
+    fn get_badge_bounty(&self, badge_nonce: u64) -> Option<Bounty<Self::Api>> {
+        let badge_bounty_mapper = self.badge_bounty(badge_nonce);
+        if badge_bounty_mapper.is_empty() {
+            None
+        } else {
+            Some(self.get_bounty_by_id(badge_bounty_mapper.get()))
+        }
+    }
+
+    #[view(getCompletionClaim)]
+    fn get_completion_claim(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+    ) -> Option<CompletionClaim<Self::Api>> {
+        let claim_mapper = self.completion_claims(&repo_owner, &repo_url, &issue_id);
+        if claim_mapper.is_empty() {
+            None
+        } else {
+            Some(claim_mapper.get())
+        }
+    }
+
+    /// Bounty keys are stored from index 1.
+    fn bounty_key_range(&self, from: usize, size: usize) -> core::ops::Range<usize> {
+        let count = self.bounty_keys().len();
+        let start = core::cmp::min(from, count);
+        let end = core::cmp::min(from.saturating_add(size), count);
+
+        start + 1..end + 1
+    }
+
+    fn get_bounty_by_id(&self, bounty_id: u64) -> Bounty<Self::Api> {
+        self.get_bounty_by_key(&self.bounty_keys().get(bounty_id as usize))
+    }
+
+    fn add_to_totals(
+        &self,
+        totals: &mut ManagedVec<TokenAmount<Self::Api>>,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        // Settled bounties hold nothing in escrow
+        if amount == &0 {
+            return;
+        }
+
+        match totals.iter().position(|total| &total.token == token) {
+            Some(index) => {
+                let mut total = totals.get(index);
+                total.amount += amount;
+                let _ = totals.set(index, total);
+            }
+            None => totals.push(TokenAmount {
+                token: token.clone(),
+                amount: amount.clone(),
+            }),
+        }
+    }
+
+    fn get_bounty_by_key(&self, bounty_key: &BountyKey<Self::Api>) -> Bounty<Self::Api> {
+        let bounty = self
+            .bounties(
+                &bounty_key.repo_owner,
+                &bounty_key.repo_url,
+                &bounty_key.issue_id,
+            )
+            .get();
+        self.with_repo_delegates(bounty)
+    }
+
+    /// Views list the proposer's repo-wide delegates next to the per-bounty
+    /// ones, so `delegates` shows everyone who can release or reject.
+    fn with_repo_delegates(&self, mut bounty: Bounty<Self::Api>) -> Bounty<Self::Api> {
+        for delegate in self
+            .repo_delegates(&bounty.proposer, &bounty.repo_owner, &bounty.repo_url)
+            .iter()
+        {
+            if !bounty.delegates.contains(&delegate) {
+                bounty.delegates.push(delegate);
+            }
+        }
+
+        bounty
+    }
+}
@@ -2,54 +2,364 @@
 
 #[allow(unused_imports)]
 use multiversx_sc::imports::*;
+use storage::BadgeAttributes;
 use storage::Bounty;
+use storage::BountyKey;
 use storage::BountyStatus;
+use storage::CompletionClaim;
+use storage::Dispute;
+use storage::IdentityProof;
+use storage::MergeAttestation;
+use storage::Milestone;
+use storage::MilestoneStatus;
 use storage::Solver;
 
 mod events;
+mod pause;
 mod storage;
+mod views;
+pub mod x_bounty_proxy;
 
-pub const TIMESTAMP_IN_30_DAYS: u64 = 60 * 60 * 24 * 30;
+pub const DEFAULT_LOCKUP: u64 = 60 * 60 * 24 * 30;
+pub const DEFAULT_MIN_LOCKUP: u64 = 60 * 60 * 24 * 7;
+pub const DEFAULT_MAX_LOCKUP: u64 = 60 * 60 * 24 * 90;
+pub const DEFAULT_MAX_SOLVERS: usize = 5;
+pub const DEFAULT_MAX_BACKERS: usize = 20;
+pub const MIN_TOP_UP_BPS: u64 = 100;
+pub const DEFAULT_WORK_PERIOD: u64 = 60 * 60 * 24 * 14;
+pub const DEFAULT_REVIEW_PERIOD: u64 = 60 * 60 * 24 * 7;
+pub const DEFAULT_DISPUTE_TIMEOUT: u64 = 60 * 60 * 24 * 30;
+pub const MAX_BASIS_POINTS: u64 = 10_000;
+pub const MAX_FEE_BPS: u64 = 1_000;
+pub const STORAGE_VERSION: u32 = 1;
+pub const IDENTITY_PROOF_TAG: &[u8] = b"xBounty:identity";
+pub const MERGE_ATTESTATION_TAG: &[u8] = b"xBounty:merge";
 
 #[multiversx_sc::contract]
-pub trait XBounty: events::EventsModule + storage::StorageModule {
+pub trait XBounty:
+    events::EventsModule + pause::PauseModule + storage::StorageModule + views::ViewsModule
+{
     #[init]
-    fn init(&self) {}
+    fn init(&self) {
+        self.storage_version().set(STORAGE_VERSION);
+        self.max_solvers().set_if_empty(DEFAULT_MAX_SOLVERS);
+        self.max_backers().set_if_empty(DEFAULT_MAX_BACKERS);
+        self.default_work_period().set_if_empty(DEFAULT_WORK_PERIOD);
+        self.review_period().set_if_empty(DEFAULT_REVIEW_PERIOD);
+        self.dispute_timeout().set_if_empty(DEFAULT_DISPUTE_TIMEOUT);
+        self.min_lockup().set_if_empty(DEFAULT_MIN_LOCKUP);
+        self.max_lockup().set_if_empty(DEFAULT_MAX_LOCKUP);
+    }
 
+    /// Bounties are stored in a layout that older deployments cannot decode,
+    /// so only contracts deployed with the current layout can be upgraded.
+    /// Bump `STORAGE_VERSION` whenever a stored struct changes shape.
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        require!(
+            self.storage_version().get() == STORAGE_VERSION,
+            "Storage layout changed, deploy a fresh contract instead"
+        );
 
-    #[payable("EGLD")]
+        self.max_solvers().set_if_empty(DEFAULT_MAX_SOLVERS);
+        self.max_backers().set_if_empty(DEFAULT_MAX_BACKERS);
+        self.default_work_period().set_if_empty(DEFAULT_WORK_PERIOD);
+        self.review_period().set_if_empty(DEFAULT_REVIEW_PERIOD);
+        self.dispute_timeout().set_if_empty(DEFAULT_DISPUTE_TIMEOUT);
+        self.min_lockup().set_if_empty(DEFAULT_MIN_LOCKUP);
+        self.max_lockup().set_if_empty(DEFAULT_MAX_LOCKUP);
+    }
+
+    #[payable("*")]
+    #[allow_multiple_var_args]
     #[endpoint]
-    fn fund(&self, repo_owner: ManagedBuffer, repo_url: ManagedBuffer, issue_id: u64) {
-        let payment_amount = self.call_value().egld_value().clone_value();
+    fn fund(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        opt_work_period: OptionalValue<u64>,
+        opt_lockup: OptionalValue<u64>,
+    ) {
+        let work_period = opt_work_period.into_option().unwrap_or_default();
+        let lockup = opt_lockup.into_option().unwrap_or_default();
+        self.create_bounty(
+            repo_owner,
+            repo_url,
+            issue_id,
+            self.blockchain().get_caller(),
+            self.call_payment(),
+            work_period,
+            lockup,
+            ManagedVec::new(),
+        );
+    }
+
+    #[payable("*")]
+    #[endpoint(fundMilestones)]
+    fn fund_milestones(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        work_period: u64,
+        lockup: u64,
+        milestones: MultiValueEncoded<MultiValue2<BigUint, ManagedBuffer>>,
+    ) {
+        let mut bounty_milestones = ManagedVec::new();
+        for milestone in milestones {
+            let (amount, description_hash) = milestone.into_tuple();
+            require!(amount > 0, "Milestone amount must be greater than 0");
+
+            bounty_milestones.push(Milestone {
+                amount,
+                description_hash,
+                status: MilestoneStatus::Pending,
+            });
+        }
+        require!(!bounty_milestones.is_empty(), "No milestones provided");
+
+        self.create_bounty(
+            repo_owner,
+            repo_url,
+            issue_id,
+            self.blockchain().get_caller(),
+            self.call_payment(),
+            work_period,
+            lockup,
+            bounty_milestones,
+        );
+    }
+
+    #[payable("*")]
+    #[allow_multiple_var_args]
+    #[endpoint(fundWithApprovers)]
+    fn fund_with_approvers(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        approval_threshold: usize,
+        approvers: ManagedVec<ManagedAddress>,
+        opt_work_period: OptionalValue<u64>,
+        opt_lockup: OptionalValue<u64>,
+    ) {
+        let mut bounty_approvers = ManagedVec::<Self::Api, ManagedAddress>::new();
+        for approver in approvers.iter() {
+            require!(!bounty_approvers.contains(&approver), "Duplicate approver");
+            bounty_approvers.push((*approver).clone());
+        }
+        require!(
+            approval_threshold > 0 && approval_threshold <= bounty_approvers.len(),
+            "Invalid approval threshold"
+        );
+
+        self.create_bounty(
+            repo_owner.clone(),
+            repo_url.clone(),
+            issue_id,
+            self.blockchain().get_caller(),
+            self.call_payment(),
+            opt_work_period.into_option().unwrap_or_default(),
+            opt_lockup.into_option().unwrap_or_default(),
+            ManagedVec::new(),
+        );
+        self.bounties(&repo_owner, &repo_url, &issue_id)
+            .update(|bounty| {
+                bounty.approvers = bounty_approvers;
+                bounty.approval_threshold = approval_threshold;
+            });
+    }
+
+    #[only_owner]
+    #[endpoint(createTreasury)]
+    fn create_treasury(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        admin: ManagedAddress,
+    ) {
+        let treasury_admin_mapper = self.treasury_admin(&repo_owner, &repo_url);
+        require!(treasury_admin_mapper.is_empty(), "Treasury already exists");
+        treasury_admin_mapper.set(&admin);
+
+        // Emit event for treasury creation
+        self.create_treasury_event(repo_owner, repo_url, admin);
+    }
+
+    #[endpoint(transferTreasuryAdmin)]
+    fn transfer_treasury_admin(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        new_admin: ManagedAddress,
+    ) {
+        let treasury_admin_mapper = self.treasury_admin(&repo_owner, &repo_url);
+        require!(!treasury_admin_mapper.is_empty(), "Treasury does not exist");
+
+        // The contract owner can recover a treasury whose admin key is lost
+        let caller = self.blockchain().get_caller();
+        let previous_admin = treasury_admin_mapper.get();
+        require!(
+            previous_admin == caller || self.blockchain().get_owner_address() == caller,
+            "Only the treasury admin can manage the treasury"
+        );
+        treasury_admin_mapper.set(&new_admin);
+
+        // Emit event for treasury admin transfer
+        self.transfer_treasury_admin_event(repo_owner, repo_url, previous_admin, new_admin);
+    }
+
+    #[payable("*")]
+    #[endpoint(depositToTreasury)]
+    fn deposit_to_treasury(&self, repo_owner: ManagedBuffer, repo_url: ManagedBuffer) {
+        self.require_not_paused();
+
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
         require!(payment_amount > 0, "Payment amount must be greater than 0");
 
-        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(
+            !self.treasury_admin(&repo_owner, &repo_url).is_empty(),
+            "Treasury does not exist"
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.add_to_treasury(&repo_owner, &repo_url, &payment_token, &payment_amount);
+
+        // Emit event for treasury deposit
+        self.treasury_deposit_event(repo_owner, repo_url, payment_token, payment_amount, caller);
+    }
+
+    #[endpoint(withdrawFromTreasury)]
+    fn withdraw_from_treasury(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_treasury_admin(&repo_owner, &repo_url, &caller);
+        self.take_from_treasury(&repo_owner, &repo_url, &token, &amount);
+
+        self.send().direct(&caller, &token, 0, &amount);
+
+        // Emit event for treasury withdrawal
+        self.treasury_withdraw_event(repo_owner, repo_url, token, amount, caller);
+    }
+
+    #[endpoint(addTreasuryMaintainer)]
+    fn add_treasury_maintainer(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        maintainer: ManagedAddress,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_treasury_admin(&repo_owner, &repo_url, &caller);
+
+        self.treasury_maintainers(&repo_owner, &repo_url)
+            .insert(maintainer);
+    }
+
+    #[endpoint(removeTreasuryMaintainer)]
+    fn remove_treasury_maintainer(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        maintainer: ManagedAddress,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_treasury_admin(&repo_owner, &repo_url, &caller);
+
+        self.treasury_maintainers(&repo_owner, &repo_url)
+            .swap_remove(&maintainer);
+    }
 
+    #[allow_multiple_var_args]
+    #[endpoint(allocateBounty)]
+    fn allocate_bounty(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        opt_work_period: OptionalValue<u64>,
+        opt_lockup: OptionalValue<u64>,
+    ) {
         require!(
-            bounties_mapper.is_empty(),
-            "Bounty already exists for this issue"
+            !self.treasury_admin(&repo_owner, &repo_url).is_empty(),
+            "Treasury does not exist"
         );
 
         let caller = self.blockchain().get_caller();
-        let current_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            self.is_treasury_spender(&repo_owner, &repo_url, &caller),
+            "Only treasury maintainers can allocate bounties"
+        );
+        self.take_from_treasury(&repo_owner, &repo_url, &token, &amount);
 
-        let bounty = Bounty {
-            repo_url: repo_url.clone(),
+        self.create_bounty(
+            repo_owner,
+            repo_url,
             issue_id,
-            repo_owner: repo_owner.clone(),
-            amount: payment_amount.clone(),
-            proposer: caller.clone(),
-            solvers: ManagedVec::new(),
-            status: BountyStatus::Funded,
-            created_at: current_timestamp,
-        };
+            self.blockchain().get_sc_address(),
+            EgldOrEsdtTokenPayment::new(token, 0, amount),
+            opt_work_period.into_option().unwrap_or_default(),
+            opt_lockup.into_option().unwrap_or_default(),
+            ManagedVec::new(),
+        );
+    }
+
+    #[payable("*")]
+    #[endpoint(topUp)]
+    fn top_up(&self, repo_owner: ManagedBuffer, repo_url: ManagedBuffer, issue_id: u64) {
+        self.require_not_paused();
+
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(payment_amount > 0, "Payment amount must be greater than 0");
+
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let mut bounty = bounties_mapper.get();
+        require!(bounty.is_open(), "Bounty is no longer open");
+        require!(bounty.token == payment_token, "Invalid payment token");
+        require!(
+            bounty.milestones.is_empty(),
+            "Milestone bounties cannot be topped up"
+        );
+
+        // Cancellation refunds every backer, so their number and the size of
+        // each top-up are bounded to keep that loop affordable
+        require!(
+            &payment_amount * MAX_BASIS_POINTS >= &bounty.amount * MIN_TOP_UP_BPS,
+            "Top-up is below the minimum contribution"
+        );
+        let caller = self.blockchain().get_caller();
+        let mut contributions_mapper = self.contributions(&repo_owner, &repo_url, &issue_id);
+        require!(
+            contributions_mapper.contains_key(&caller)
+                || contributions_mapper.len() < self.max_backers().get(),
+            "Maximum number of backers reached"
+        );
 
+        bounty.amount += &payment_amount;
         bounties_mapper.set(&bounty);
 
-        // Emit event for funding
-        self.fund_event(repo_owner, repo_url, issue_id, payment_amount, caller);
+        let contribution = contributions_mapper.get(&caller).unwrap_or_default() + &payment_amount;
+        contributions_mapper.insert(caller.clone(), contribution);
+
+        // Emit event for top up
+        self.top_up_event(
+            repo_owner,
+            repo_url,
+            issue_id,
+            payment_token,
+            payment_amount,
+            caller,
+        );
     }
 
     #[endpoint(cancelBounty)]
@@ -57,47 +367,130 @@ pub trait XBounty: events::EventsModule + storage::StorageModule {
         let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
         require!(!bounties_mapper.is_empty(), "Bounty does not exist");
 
-        let bounty = bounties_mapper.get();
+        let mut bounty = bounties_mapper.get();
+        require!(bounty.is_open(), "Bounty cannot be cancelled");
 
         let caller = self.blockchain().get_caller();
         require!(
-            bounty.proposer == caller,
+            self.is_bounty_owner(&bounty, &caller),
             "Only proposer can cancel the bounty"
         );
 
-        let block_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            self.pending_claim(&bounty).is_none(),
+            "A completion claim is under review"
+        );
 
+        // The lockup only protects solvers, so it is skipped if none ever registered
+        let block_timestamp = self.blockchain().get_block_timestamp();
         require!(
-            block_timestamp > bounty.created_at + TIMESTAMP_IN_30_DAYS,
-            "Cancel may be performed after a 30 days delay"
+            !bounty.was_registered || block_timestamp > bounty.created_at + bounty.lockup,
+            "Cancel may be performed after the lockup period"
         );
 
-        self.send().direct_egld(&caller, &bounty.amount);
+        // Refund every backer their own share
+        let mut refunded_amount = BigUint::zero();
+        let mut contributions_mapper = self.contributions(&repo_owner, &repo_url, &issue_id);
+        for (backer, contribution) in contributions_mapper.iter() {
+            self.refund_backer(&bounty, &backer, &contribution);
+            refunded_amount += contribution;
+        }
+        contributions_mapper.clear();
+
+        // Milestones already paid out stay with the solver
+        bounty.amount = bounty.paid_amount();
+        bounty.status = if bounty.amount > 0 {
+            BountyStatus::Completed
+        } else {
+            BountyStatus::Cancelled
+        };
+        for index in 0..bounty.milestones.len() {
+            let mut milestone = bounty.milestones.get(index);
+            if milestone.status == MilestoneStatus::Pending {
+                milestone.status = MilestoneStatus::Cancelled;
+                let _ = bounty.milestones.set(index, milestone);
+            }
+        }
+        bounties_mapper.set(&bounty);
+        self.untrack_open_bounty(&bounty);
+        self.refund_stakes(&bounty);
+        self.record_payee_completions(&bounty);
+
+        // Emit event for cancellation
+        self.cancel_event(
+            repo_owner,
+            repo_url,
+            issue_id,
+            bounty.token,
+            refunded_amount,
+            caller,
+        );
     }
 
+    #[payable("*")]
     #[endpoint]
-    fn register(
-        &self,
-        repo_owner: ManagedBuffer,
-        repo_url: ManagedBuffer,
-        issue_id: u64,
-        solver_github: ManagedBuffer,
-    ) {
+    fn register(&self, repo_owner: ManagedBuffer, repo_url: ManagedBuffer, issue_id: u64) {
+        self.require_not_paused();
+
         let caller = self.blockchain().get_caller();
+        let solver_github_mapper = self.solvers(&caller);
+        require!(
+            !solver_github_mapper.is_empty(),
+            "Address is not linked to a GitHub account"
+        );
+        let solver_github = solver_github_mapper.get();
+
         let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
         require!(!bounties_mapper.is_empty(), "Bounty does not exist");
 
         let mut bounty = bounties_mapper.get();
         require!(
-            bounty.status == BountyStatus::Funded,
-            "Bounty is not in funded status"
+            bounty.status == BountyStatus::Funded || bounty.status == BountyStatus::Registered,
+            "Bounty is not open for registration"
+        );
+        require!(
+            !bounty
+                .solvers
+                .iter()
+                .any(|solver| solver.solver_addr == caller),
+            "Solver is already registered"
+        );
+        require!(
+            !bounty
+                .solvers
+                .iter()
+                .any(|solver| solver.solver_github == solver_github),
+            "GitHub account is already registered"
+        );
+        require!(
+            !self
+                .rejected_solvers(&repo_owner, &repo_url, &issue_id)
+                .contains(&caller),
+            "Solver was rejected from this bounty"
+        );
+        require!(
+            bounty.solvers.len() < self.max_solvers().get(),
+            "Maximum number of solvers reached"
+        );
+
+        let (stake_token, stake) = self.call_value().egld_or_single_fungible_esdt();
+        require!(
+            stake == bounty.registration_stake && (stake == 0 || stake_token == bounty.token),
+            "Invalid registration stake"
         );
 
+        let current_timestamp = self.blockchain().get_block_timestamp();
         bounty.solvers.push(Solver {
             solver_addr: caller.clone(),
             solver_github: solver_github.clone(),
+            deadline: current_timestamp + bounty.work_period,
+            stake,
         });
+        self.bounties_by_solver(&caller).insert(bounty.id);
+        self.solver_stats(&caller)
+            .update(|stats| stats.registrations += 1);
         bounty.status = BountyStatus::Registered;
+        bounty.was_registered = true;
 
         bounties_mapper.set(&bounty);
 
@@ -105,72 +498,1626 @@ pub trait XBounty: events::EventsModule + storage::StorageModule {
         self.claim_event(repo_owner, repo_url, issue_id, caller, solver_github);
     }
 
-    #[endpoint(releaseBounty)]
-    fn release_bounty(
+    #[endpoint(setRegistrationStake)]
+    fn set_registration_stake(
         &self,
         repo_owner: ManagedBuffer,
         repo_url: ManagedBuffer,
         issue_id: u64,
-        solver_addr: ManagedAddress,
-        solver_github: ManagedBuffer,
+        registration_stake: BigUint,
     ) {
         let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
         require!(!bounties_mapper.is_empty(), "Bounty does not exist");
 
-        let bounty = bounties_mapper.get();
+        let mut bounty = bounties_mapper.get();
+        require!(bounty.is_open(), "Bounty is no longer open");
+
+        let caller = self.blockchain().get_caller();
         require!(
-            bounty.status == BountyStatus::Registered,
-            "Bounty is not in claimed status"
+            self.is_bounty_owner(&bounty, &caller),
+            "Only proposer can set the registration stake"
         );
 
+        // Solvers already registered keep the stake they locked
+        bounty.registration_stake = registration_stake;
+        bounties_mapper.set(&bounty);
+    }
+
+    #[endpoint(linkGithub)]
+    fn link_github(&self, solver_github: ManagedBuffer, signature: ManagedBuffer) {
+        let signer_mapper = self.attestation_signer();
+        require!(!signer_mapper.is_empty(), "Attestation signer is not set");
+        require!(!solver_github.is_empty(), "GitHub login cannot be empty");
+
         let caller = self.blockchain().get_caller();
+        let solver_github_mapper = self.solvers(&caller);
+        require!(
+            solver_github_mapper.is_empty(),
+            "Address is already linked to a GitHub account"
+        );
+        let solver_address_mapper = self.solver_address(&solver_github);
         require!(
-            bounty.proposer == caller,
-            "Only proposer can release the bounty"
+            solver_address_mapper.is_empty(),
+            "GitHub account is already linked to an address"
         );
 
-        let solvers_addrs = bounty.solvers.clone();
+        let proof = IdentityProof {
+            solver_addr: caller.clone(),
+            solver_github: solver_github.clone(),
+        };
+        let message = self.signed_message(IDENTITY_PROOF_TAG, &proof);
+        self.crypto()
+            .verify_ed25519(&signer_mapper.get(), &message, &signature);
+
+        solver_github_mapper.set(&solver_github);
+        solver_address_mapper.set(&caller);
+
+        // Emit event for identity link
+        self.link_github_event(caller, solver_github);
+    }
 
+    /// Lets the owner release a login from a lost or wrongly linked wallet so
+    /// it can be linked again. Registrations of the old wallet stay listed
+    /// but can no longer be paid by the oracle, attestations or claims.
+    #[only_owner]
+    #[endpoint(unlinkGithub)]
+    fn unlink_github(&self, solver_addr: ManagedAddress) {
+        let solver_github_mapper = self.solvers(&solver_addr);
         require!(
-            solvers_addrs.contains(&Solver {
-                solver_addr: solver_addr.clone(),
-                solver_github: solver_github.clone()
-            }),
-            "Solver wasn't previously registered"
+            !solver_github_mapper.is_empty(),
+            "Address is not linked to a GitHub account"
         );
 
-        self.send().direct_egld(&solver_addr, &bounty.amount);
+        let solver_github = solver_github_mapper.take();
+        self.solver_address(&solver_github).clear();
 
-        // Update status
-        let mut updated_bounty = bounty.clone();
-        updated_bounty.status = BountyStatus::Completed;
-        bounties_mapper.set(&updated_bounty);
+        // Emit event for identity unlink
+        self.unlink_github_event(solver_addr, solver_github);
+    }
 
-        // Emit event for completion
-        self.complete_event(
+    #[endpoint(withdrawRegistration)]
+    fn withdraw_registration(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+    ) {
+        let caller = self.blockchain().get_caller();
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let mut bounty = bounties_mapper.get();
+        require!(
+            bounty.status == BountyStatus::Registered,
+            "Bounty is not in claimed status"
+        );
+
+        let solver_index = self.require_solver_index(&bounty.solvers, &caller);
+        let solver = bounty.solvers.get(solver_index);
+        bounty.solvers.remove(solver_index);
+        self.bounties_by_solver(&caller).swap_remove(&bounty.id);
+        self.clear_claim_of(&bounty, &caller);
+        if bounty.solvers.is_empty() {
+            bounty.status = BountyStatus::Funded;
+        }
+
+        bounties_mapper.set(&bounty);
+        self.send()
+            .direct_non_zero(&caller, &bounty.token, 0, &solver.stake);
+
+        // Emit event for withdrawal
+        self.withdraw_registration_event(
             repo_owner,
             repo_url,
             issue_id,
-            solver_addr,
-            solver_github,
-            bounty.amount,
+            caller,
+            solver.solver_github,
         );
     }
 
-    // Views
-
-    #[view(getBounty)]
-    fn get_bounty(
+    #[endpoint(rejectSolver)]
+    fn reject_solver(
         &self,
         repo_owner: ManagedBuffer,
         repo_url: ManagedBuffer,
         issue_id: u64,
-    ) -> Option<Bounty<Self::Api>> {
+        solver_addr: ManagedAddress,
+        is_spam: bool,
+        opt_reason_hash: OptionalValue<ManagedBuffer>,
+    ) {
         let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
-        if bounties_mapper.is_empty() {
-            None
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let mut bounty = bounties_mapper.get();
+        require!(
+            bounty.status == BountyStatus::Registered,
+            "Bounty is not in claimed status"
+        );
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_proposer_or_delegate(&bounty, &caller),
+            "Only proposer or delegates can reject solvers"
+        );
+
+        let solver_index = self.require_solver_index(&bounty.solvers, &solver_addr);
+        require!(
+            self.pending_claim(&bounty)
+                .is_none_or(|claim| claim.solver_addr != solver_addr),
+            "Solver has a completion claim under review"
+        );
+
+        let solver = bounty.solvers.get(solver_index);
+        bounty.solvers.remove(solver_index);
+        self.bounties_by_solver(&solver_addr)
+            .swap_remove(&bounty.id);
+        self.rejected_solvers(&repo_owner, &repo_url, &issue_id)
+            .insert(solver_addr.clone());
+        if bounty.solvers.is_empty() {
+            bounty.status = BountyStatus::Funded;
+        }
+
+        bounties_mapper.set(&bounty);
+        if is_spam {
+            self.slash_stake(&bounty, &solver.stake);
         } else {
-            Some(bounties_mapper.get())
+            self.send()
+                .direct_non_zero(&solver_addr, &bounty.token, 0, &solver.stake);
+        }
+
+        // Emit event for rejection
+        let reason_hash = opt_reason_hash.into_option().unwrap_or_default();
+        self.reject_solver_event(
+            repo_owner,
+            repo_url,
+            issue_id,
+            solver_addr,
+            solver.solver_github,
+            caller,
+            reason_hash,
+        );
+    }
+
+    #[endpoint(expireRegistrations)]
+    fn expire_registrations(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+    ) {
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let mut bounty = bounties_mapper.get();
+        require!(
+            bounty.status == BountyStatus::Registered,
+            "Bounty is not in claimed status"
+        );
+
+        // A solver whose completion claim is under review has delivered in time
+        let claimant = self.pending_claim(&bounty).map(|claim| claim.solver_addr);
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut active_solvers = ManagedVec::new();
+        let mut expired_solvers = ManagedVec::<Self::Api, Solver<Self::Api>>::new();
+        for solver in bounty.solvers.iter() {
+            if current_timestamp > solver.deadline && claimant.as_ref() != Some(&solver.solver_addr)
+            {
+                expired_solvers.push(solver);
+            } else {
+                active_solvers.push(solver);
+            }
+        }
+        require!(
+            !expired_solvers.is_empty(),
+            "No registration has expired yet"
+        );
+
+        bounty.solvers = active_solvers;
+        if bounty.solvers.is_empty() {
+            bounty.status = BountyStatus::Funded;
+        }
+        bounties_mapper.set(&bounty);
+
+        for solver in expired_solvers.iter() {
+            self.bounties_by_solver(&solver.solver_addr)
+                .swap_remove(&bounty.id);
+            self.slash_stake(&bounty, &solver.stake);
+
+            // Emit event for each evicted solver
+            self.expire_registration_event(
+                repo_owner.clone(),
+                repo_url.clone(),
+                issue_id,
+                solver.solver_addr,
+                solver.solver_github,
+            );
+        }
+    }
+
+    #[endpoint(releaseBounty)]
+    fn release_bounty(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        solver_addr: ManagedAddress,
+    ) {
+        self.require_not_paused();
+
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let bounty = bounties_mapper.get();
+        require!(
+            bounty.status == BountyStatus::Registered,
+            "Bounty is not in claimed status"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let solver_index = self.require_solver_index(&bounty.solvers, &solver_addr);
+        let solver = bounty.solvers.get(solver_index);
+        if bounty.approvers.is_empty() {
+            require!(
+                self.is_proposer_or_delegate(&bounty, &caller),
+                "Only proposer or delegates can release the bounty"
+            );
+
+            self.pay_solver(bounty, solver);
+            return;
+        }
+
+        // Approver bounties only pay out once enough approvers agree on the solver
+        require!(
+            bounty.approvers.contains(&caller),
+            "Only approvers can release the bounty"
+        );
+        let mut approvals_mapper = self.release_approvals(&repo_owner, &repo_url, &issue_id);
+        approvals_mapper.insert(caller.clone(), solver_addr.clone());
+
+        // Emit event for approval
+        self.approve_release_event(repo_owner, repo_url, issue_id, caller, solver_addr.clone());
+
+        let approvals = approvals_mapper
+            .values()
+            .filter(|approved_solver| approved_solver == &solver_addr)
+            .count();
+        if approvals >= bounty.approval_threshold {
+            approvals_mapper.clear();
+            self.pay_solver(bounty, solver);
+        }
+    }
+
+    #[endpoint(addDelegate)]
+    fn add_delegate(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        delegate: ManagedAddress,
+    ) {
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let mut bounty = bounties_mapper.get();
+        require!(bounty.is_open(), "Bounty is no longer open");
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_bounty_owner(&bounty, &caller),
+            "Only proposer can manage delegates"
+        );
+        require!(
+            !bounty.delegates.contains(&delegate),
+            "Address is already a delegate"
+        );
+
+        bounty.delegates.push(delegate.clone());
+        bounties_mapper.set(&bounty);
+
+        // Emit event for added delegate
+        self.add_delegate_event(repo_owner, repo_url, issue_id, caller, delegate);
+    }
+
+    #[endpoint(removeDelegate)]
+    fn remove_delegate(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        delegate: ManagedAddress,
+    ) {
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let mut bounty = bounties_mapper.get();
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_bounty_owner(&bounty, &caller),
+            "Only proposer can manage delegates"
+        );
+
+        let delegate_index = match bounty.delegates.find(&delegate) {
+            Some(index) => index,
+            None => sc_panic!("Address is not a delegate"),
+        };
+        bounty.delegates.remove(delegate_index);
+        bounties_mapper.set(&bounty);
+
+        // Emit event for removed delegate
+        self.remove_delegate_event(repo_owner, repo_url, issue_id, caller, delegate);
+    }
+
+    #[endpoint(addRepoDelegate)]
+    fn add_repo_delegate(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        delegate: ManagedAddress,
+    ) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.repo_delegates(&caller, &repo_owner, &repo_url)
+                .insert(delegate.clone()),
+            "Address is already a delegate"
+        );
+
+        // Emit event for added repo delegate
+        self.add_repo_delegate_event(repo_owner, repo_url, caller, delegate);
+    }
+
+    #[endpoint(removeRepoDelegate)]
+    fn remove_repo_delegate(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        delegate: ManagedAddress,
+    ) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.repo_delegates(&caller, &repo_owner, &repo_url)
+                .swap_remove(&delegate),
+            "Address is not a delegate"
+        );
+
+        // Emit event for removed repo delegate
+        self.remove_repo_delegate_event(repo_owner, repo_url, caller, delegate);
+    }
+
+    #[endpoint(revokeApproval)]
+    fn revoke_approval(&self, repo_owner: ManagedBuffer, repo_url: ManagedBuffer, issue_id: u64) {
+        let caller = self.blockchain().get_caller();
+        let approval = self
+            .release_approvals(&repo_owner, &repo_url, &issue_id)
+            .remove(&caller);
+        require!(approval.is_some(), "No approval to revoke");
+
+        // Emit event for revoked approval
+        self.revoke_approval_event(repo_owner, repo_url, issue_id, caller);
+    }
+
+    #[endpoint(releaseMilestone)]
+    fn release_milestone(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        solver_addr: ManagedAddress,
+    ) {
+        self.require_not_paused();
+
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let mut bounty = bounties_mapper.get();
+        require!(
+            bounty.status == BountyStatus::Registered,
+            "Bounty is not in claimed status"
+        );
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_proposer_or_delegate(&bounty, &caller),
+            "Only proposer or delegates can release the bounty"
+        );
+        self.require_solver_index(&bounty.solvers, &solver_addr);
+
+        let milestone_index = match bounty.next_pending_milestone() {
+            Some(index) => index,
+            None => sc_panic!("Bounty has no pending milestones"),
+        };
+
+        let mut milestone = bounty.milestones.get(milestone_index);
+        milestone.status = MilestoneStatus::Paid;
+        let _ = bounty.milestones.set(milestone_index, milestone.clone());
+        self.add_payee(&bounty, &solver_addr, &milestone.amount);
+        if bounty.next_pending_milestone().is_none() {
+            bounty.status = BountyStatus::Completed;
+            self.untrack_open_bounty(&bounty);
+            self.refund_stakes(&bounty);
+            self.record_payee_completions(&bounty);
+        }
+        bounties_mapper.set(&bounty);
+
+        // The proposer is the only backer of a milestone bounty
+        let mut contributions_mapper = self.contributions(&repo_owner, &repo_url, &issue_id);
+        let contribution = contributions_mapper
+            .get(&bounty.proposer)
+            .unwrap_or_default();
+        contributions_mapper.insert(bounty.proposer.clone(), contribution - &milestone.amount);
+
+        let fee = self.pay_with_fee(&solver_addr, &bounty.token, &milestone.amount);
+
+        // Emit event for milestone payment
+        self.milestone_event(
+            repo_owner,
+            repo_url,
+            issue_id,
+            milestone_index,
+            solver_addr,
+            bounty.token,
+            milestone.amount,
+            fee,
+        );
+    }
+
+    #[endpoint(releaseSplit)]
+    fn release_split(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        dust_recipient: ManagedAddress,
+        shares: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>,
+    ) {
+        self.require_not_paused();
+
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let mut bounty = bounties_mapper.get();
+        require!(
+            bounty.status == BountyStatus::Registered,
+            "Bounty is not in claimed status"
+        );
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_proposer_or_delegate(&bounty, &caller),
+            "Only proposer or delegates can release the bounty"
+        );
+        require!(
+            bounty.milestones.is_empty(),
+            "Milestone bounties are released per milestone"
+        );
+        require!(
+            bounty.approvers.is_empty(),
+            "Bounty requires approvals to release"
+        );
+
+        let mut total_share = 0u64;
+        let mut payees = ManagedVec::<Self::Api, MultiValue2<Solver<Self::Api>, u64>>::new();
+        for share in shares {
+            let (solver_addr, share_bps) = share.into_tuple();
+            require!(share_bps > 0, "Share must be greater than 0");
+            require!(
+                !payees
+                    .iter()
+                    .any(|payee| payee.into_tuple().0.solver_addr == solver_addr),
+                "Duplicate payee"
+            );
+
+            let solver_index = self.require_solver_index(&bounty.solvers, &solver_addr);
+            payees.push((bounty.solvers.get(solver_index), share_bps).into());
+            total_share += share_bps;
+        }
+        require!(
+            total_share == MAX_BASIS_POINTS,
+            "Shares must add up to 10000 basis points"
+        );
+
+        bounty.status = BountyStatus::Completed;
+        bounties_mapper.set(&bounty);
+        self.untrack_open_bounty(&bounty);
+        self.refund_stakes(&bounty);
+        self.completion_claims(&repo_owner, &repo_url, &issue_id)
+            .clear();
+
+        let mut total_paid = BigUint::zero();
+        for payee in payees.iter() {
+            let (solver, share_bps) = payee.into_tuple();
+            let payout = &bounty.amount * share_bps / MAX_BASIS_POINTS;
+            let fee = self.pay_with_fee(&solver.solver_addr, &bounty.token, &payout);
+            self.record_completion(&bounty, &solver.solver_addr, &payout);
+            total_paid += &payout;
+
+            // Emit event for each payee
+            self.complete_event(
+                repo_owner.clone(),
+                repo_url.clone(),
+                issue_id,
+                solver.solver_addr,
+                solver.solver_github,
+                bounty.token.clone(),
+                payout,
+                fee,
+            );
+        }
+
+        let dust = &bounty.amount - &total_paid;
+        self.send()
+            .direct_non_zero(&dust_recipient, &bounty.token, 0, &dust);
+    }
+
+    #[endpoint(releaseByOracle)]
+    fn release_by_oracle(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        pr_number: u64,
+        solver_github: ManagedBuffer,
+        solver_addr: ManagedAddress,
+    ) {
+        self.require_not_paused();
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.oracles().contains(&caller),
+            "Only oracles can release the bounty"
+        );
+
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let bounty = bounties_mapper.get();
+        require!(
+            bounty.status == BountyStatus::Registered,
+            "Bounty is not in claimed status"
+        );
+        require!(
+            bounty.approvers.is_empty(),
+            "Bounty requires approvals to release"
+        );
+
+        let solver = self.require_linked_solver(&bounty, &solver_addr, &solver_github);
+        self.pay_solver(bounty, solver);
+
+        // Emit event for the merged PR reported by the oracle
+        self.oracle_release_event(repo_owner, repo_url, issue_id, pr_number, caller);
+    }
+
+    #[endpoint(claimWithAttestation)]
+    fn claim_with_attestation(
+        &self,
+        attestation: MergeAttestation<Self::Api>,
+        signature: ManagedBuffer,
+    ) {
+        self.require_not_paused();
+
+        let signer_mapper = self.attestation_signer();
+        require!(!signer_mapper.is_empty(), "Attestation signer is not set");
+
+        let block_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            block_timestamp <= attestation.expiry,
+            "Attestation has expired"
+        );
+        require!(
+            !self.used_nonces().contains(&attestation.nonce),
+            "Attestation nonce was already used"
+        );
+
+        let message = self.signed_message(MERGE_ATTESTATION_TAG, &attestation);
+        self.crypto()
+            .verify_ed25519(&signer_mapper.get(), &message, &signature);
+
+        self.used_nonces().add(&attestation.nonce);
+
+        let bounties_mapper = self.bounties(
+            &attestation.repo_owner,
+            &attestation.repo_url,
+            &attestation.issue_id,
+        );
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let bounty = bounties_mapper.get();
+        require!(
+            bounty.status == BountyStatus::Registered,
+            "Bounty is not in claimed status"
+        );
+        require!(
+            bounty.approvers.is_empty(),
+            "Bounty requires approvals to release"
+        );
+
+        let solver = self.require_linked_solver(
+            &bounty,
+            &attestation.solver_addr,
+            &attestation.solver_github,
+        );
+        self.pay_solver(bounty, solver);
+
+        // Emit event for the merged PR attested by the backend
+        self.attestation_release_event(
+            attestation.repo_owner,
+            attestation.repo_url,
+            attestation.issue_id,
+            attestation.pr_number,
+            attestation.nonce,
+        );
+    }
+
+    #[endpoint(openDispute)]
+    fn open_dispute(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        reason_hash: ManagedBuffer,
+    ) {
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let bounty = bounties_mapper.get();
+        require!(
+            bounty.status == BountyStatus::Registered,
+            "Bounty is not in claimed status"
+        );
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            bounty.proposer == caller
+                || bounty
+                    .solvers
+                    .iter()
+                    .any(|solver| solver.solver_addr == caller),
+            "Only the proposer or a registered solver can open a dispute"
+        );
+        self.start_dispute(bounty, caller, reason_hash);
+    }
+
+    #[endpoint(submitCompletion)]
+    fn submit_completion(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        pr_reference: ManagedBuffer,
+    ) {
+        self.require_not_paused();
+
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let bounty = bounties_mapper.get();
+        require!(
+            bounty.status == BountyStatus::Registered,
+            "Bounty is not in claimed status"
+        );
+        require!(
+            bounty.milestones.is_empty(),
+            "Milestone bounties are released per milestone"
+        );
+        require!(
+            bounty.approvers.is_empty(),
+            "Bounty requires approvals to release"
+        );
+        require!(
+            self.pending_claim(&bounty).is_none(),
+            "A completion claim is already under review"
+        );
+        // An expired dispute leaves no way to object, so the review flow ends there
+        require!(
+            self.disputes(&repo_owner, &repo_url, &issue_id).is_empty(),
+            "Bounty was already disputed"
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.require_linked_solver(&bounty, &caller, &self.solvers(&caller).get());
+
+        self.completion_claims(&repo_owner, &repo_url, &issue_id)
+            .set(CompletionClaim {
+                solver_addr: caller.clone(),
+                pr_reference: pr_reference.clone(),
+                submitted_at: self.blockchain().get_block_timestamp(),
+            });
+
+        // Emit event for completion claim
+        self.submit_completion_event(repo_owner, repo_url, issue_id, caller, pr_reference);
+    }
+
+    #[endpoint(objectCompletion)]
+    fn object_completion(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        reason_hash: ManagedBuffer,
+    ) {
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let bounty = bounties_mapper.get();
+        require!(
+            bounty.status == BountyStatus::Registered,
+            "Bounty is not in claimed status"
+        );
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.is_proposer_or_delegate(&bounty, &caller),
+            "Only proposer or delegates can object"
+        );
+
+        let claim = match self.pending_claim(&bounty) {
+            Some(claim) => claim,
+            None => sc_panic!("No completion claim is under review"),
+        };
+        let block_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            block_timestamp <= claim.submitted_at + self.review_period().get(),
+            "Review window has ended"
+        );
+
+        self.start_dispute(bounty, caller, reason_hash);
+    }
+
+    #[endpoint]
+    fn finalize(&self, repo_owner: ManagedBuffer, repo_url: ManagedBuffer, issue_id: u64) {
+        self.require_not_paused();
+
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let bounty = bounties_mapper.get();
+        require!(
+            bounty.status == BountyStatus::Registered,
+            "Bounty is not in claimed status"
+        );
+
+        let claim = match self.pending_claim(&bounty) {
+            Some(claim) => claim,
+            None => sc_panic!("No completion claim is under review"),
+        };
+        let block_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            block_timestamp > claim.submitted_at + self.review_period().get(),
+            "Review window has not ended yet"
+        );
+
+        let solver_index = self.require_solver_index(&bounty.solvers, &claim.solver_addr);
+        let solver = bounty.solvers.get(solver_index);
+        self.pay_solver(bounty, solver);
+
+        // Emit event for the unopposed claim
+        let caller = self.blockchain().get_caller();
+        self.finalize_event(repo_owner, repo_url, issue_id, claim.pr_reference, caller);
+    }
+
+    /// Unfreezes a dispute the arbiters left open for too long. The bounty
+    /// goes back to `Registered` and keeps its dispute record, so no new
+    /// dispute or completion claim can stall cancellation again.
+    #[endpoint(expireDispute)]
+    fn expire_dispute(&self, repo_owner: ManagedBuffer, repo_url: ManagedBuffer, issue_id: u64) {
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let mut bounty = bounties_mapper.get();
+        require!(
+            bounty.status == BountyStatus::Disputed,
+            "Bounty is not disputed"
+        );
+
+        let dispute = self.disputes(&repo_owner, &repo_url, &issue_id).get();
+        let block_timestamp = self.blockchain().get_block_timestamp();
+        require!(
+            block_timestamp > dispute.opened_at + self.dispute_timeout().get(),
+            "Dispute has not timed out yet"
+        );
+
+        bounty.status = BountyStatus::Registered;
+        bounties_mapper.set(&bounty);
+
+        // Emit event for expired dispute
+        let caller = self.blockchain().get_caller();
+        self.expire_dispute_event(repo_owner, repo_url, issue_id, caller);
+    }
+
+    #[endpoint(resolveDispute)]
+    fn resolve_dispute(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        solver_addr: ManagedAddress,
+        solver_share_bps: u64,
+    ) {
+        self.require_not_paused();
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.arbiters().contains(&caller),
+            "Only arbiters can resolve disputes"
+        );
+        require!(
+            solver_share_bps <= MAX_BASIS_POINTS,
+            "Solver share cannot exceed 10000 basis points"
+        );
+
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        require!(!bounties_mapper.is_empty(), "Bounty does not exist");
+
+        let mut bounty = bounties_mapper.get();
+        require!(
+            bounty.status == BountyStatus::Disputed,
+            "Bounty is not disputed"
+        );
+
+        let escrow = bounty.escrowed_amount();
+        let payout = &escrow * solver_share_bps / MAX_BASIS_POINTS;
+
+        // Refund every backer their share of what the solver does not receive
+        let refund_bps = MAX_BASIS_POINTS - solver_share_bps;
+        let mut refunded_amount = BigUint::zero();
+        let mut contributions_mapper = self.contributions(&repo_owner, &repo_url, &issue_id);
+        for (backer, contribution) in contributions_mapper.iter() {
+            let refund = contribution * refund_bps / MAX_BASIS_POINTS;
+            self.refund_backer(&bounty, &backer, &refund);
+            refunded_amount += refund;
+        }
+        contributions_mapper.clear();
+
+        // Rounding dust goes back to the proposer
+        let dust = &escrow - &payout - &refunded_amount;
+        self.send()
+            .direct_non_zero(&bounty.proposer, &bounty.token, 0, &dust);
+        refunded_amount += dust;
+
+        for index in 0..bounty.milestones.len() {
+            let mut milestone = bounty.milestones.get(index);
+            if milestone.status == MilestoneStatus::Pending {
+                milestone.status = MilestoneStatus::Cancelled;
+                let _ = bounty.milestones.set(index, milestone);
+            }
+        }
+        bounty.amount = bounty.paid_amount() + &payout;
+        bounty.status = if bounty.amount > 0 {
+            BountyStatus::Completed
+        } else {
+            BountyStatus::Cancelled
+        };
+        bounties_mapper.set(&bounty);
+        self.untrack_open_bounty(&bounty);
+        self.refund_stakes(&bounty);
+        self.disputes(&repo_owner, &repo_url, &issue_id).clear();
+        self.completion_claims(&repo_owner, &repo_url, &issue_id)
+            .clear();
+
+        if payout > 0 {
+            let solver_index = self.require_solver_index(&bounty.solvers, &solver_addr);
+            let solver = bounty.solvers.get(solver_index);
+            let fee = self.pay_with_fee(&solver_addr, &bounty.token, &payout);
+            self.add_payee(&bounty, &solver_addr, &payout);
+
+            // Emit event for completion
+            self.complete_event(
+                repo_owner.clone(),
+                repo_url.clone(),
+                issue_id,
+                solver_addr.clone(),
+                solver.solver_github,
+                bounty.token.clone(),
+                payout.clone(),
+                fee,
+            );
+        }
+
+        self.record_payee_completions(&bounty);
+
+        // Emit event for the arbiter decision
+        self.resolve_dispute_event(
+            repo_owner,
+            repo_url,
+            issue_id,
+            caller,
+            solver_addr,
+            solver_share_bps,
+            payout,
+            refunded_amount,
+        );
+    }
+
+    // Admin
+
+    #[only_owner]
+    #[endpoint(addOracle)]
+    fn add_oracle(&self, oracle: ManagedAddress) {
+        self.oracles().insert(oracle);
+    }
+
+    #[only_owner]
+    #[endpoint(removeOracle)]
+    fn remove_oracle(&self, oracle: ManagedAddress) {
+        self.oracles().swap_remove(&oracle);
+    }
+
+    #[only_owner]
+    #[endpoint(addArbiter)]
+    fn add_arbiter(&self, arbiter: ManagedAddress) {
+        self.arbiters().insert(arbiter);
+    }
+
+    #[only_owner]
+    #[endpoint(removeArbiter)]
+    fn remove_arbiter(&self, arbiter: ManagedAddress) {
+        self.arbiters().swap_remove(&arbiter);
+    }
+
+    #[only_owner]
+    #[endpoint(setMaxSolvers)]
+    fn set_max_solvers(&self, max_solvers: usize) {
+        require!(
+            max_solvers > 0,
+            "Maximum number of solvers must be greater than 0"
+        );
+
+        self.max_solvers().set(max_solvers);
+    }
+
+    #[only_owner]
+    #[endpoint(setMaxBackers)]
+    fn set_max_backers(&self, max_backers: usize) {
+        require!(
+            max_backers > 0,
+            "Maximum number of backers must be greater than 0"
+        );
+
+        self.max_backers().set(max_backers);
+    }
+
+    #[only_owner]
+    #[endpoint(setDefaultWorkPeriod)]
+    fn set_default_work_period(&self, work_period: u64) {
+        require!(work_period > 0, "Work period must be greater than 0");
+
+        self.default_work_period().set(work_period);
+    }
+
+    #[only_owner]
+    #[endpoint(setReviewPeriod)]
+    fn set_review_period(&self, review_period: u64) {
+        require!(review_period > 0, "Review period must be greater than 0");
+
+        self.review_period().set(review_period);
+    }
+
+    #[only_owner]
+    #[endpoint(setDisputeTimeout)]
+    fn set_dispute_timeout(&self, dispute_timeout: u64) {
+        require!(
+            dispute_timeout > 0,
+            "Dispute timeout must be greater than 0"
+        );
+
+        self.dispute_timeout().set(dispute_timeout);
+    }
+
+    #[only_owner]
+    #[endpoint(setLockupBounds)]
+    fn set_lockup_bounds(&self, min_lockup: u64, max_lockup: u64) {
+        require!(
+            min_lockup <= max_lockup,
+            "Minimum lockup cannot exceed maximum lockup"
+        );
+
+        self.min_lockup().set(min_lockup);
+        self.max_lockup().set(max_lockup);
+    }
+
+    #[only_owner]
+    #[endpoint(setFee)]
+    fn set_fee(&self, fee_bps: u64) {
+        require!(
+            fee_bps <= MAX_FEE_BPS,
+            "Fee cannot exceed 1000 basis points"
+        );
+
+        self.fee_bps().set(fee_bps);
+    }
+
+    #[only_owner]
+    #[endpoint(withdrawFees)]
+    fn withdraw_fees(&self, token: EgldOrEsdtTokenIdentifier) {
+        let amount = self.collected_fees().remove(&token).unwrap_or_default();
+        require!(amount > 0, "No fees collected for this token");
+
+        let caller = self.blockchain().get_caller();
+        self.send().direct(&caller, &token, 0, &amount);
+
+        // Emit event for fee withdrawal
+        self.withdraw_fees_event(caller, token, amount);
+    }
+
+    #[only_owner]
+    #[endpoint(setAttestationSigner)]
+    fn set_attestation_signer(&self, public_key: ManagedBuffer) {
+        require!(public_key.len() == 32, "Invalid public key length");
+
+        self.attestation_signer().set(public_key);
+    }
+
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueBadgeCollection)]
+    fn issue_badge_collection(&self, display_name: ManagedBuffer, ticker: ManagedBuffer) {
+        let issue_cost = self.call_value().egld_value().clone_value();
+        self.badge_token().issue_and_set_all_roles(
+            EsdtTokenType::NonFungible,
+            issue_cost,
+            display_name,
+            ticker,
+            0,
+            Some(
+                self.callbacks()
+                    .badge_issue_callback(self.blockchain().get_caller()),
+            ),
+        );
+    }
+
+    /// Holding the transfer role restricts badge transfers to this contract,
+    /// which makes the badges soulbound once they reach the solver. No badge
+    /// is minted before this role is set.
+    #[only_owner]
+    #[endpoint(setBadgeTransferRole)]
+    fn set_badge_transfer_role(&self) {
+        self.badge_token()
+            .set_local_roles(&[EsdtLocalRole::Transfer], None);
+    }
+
+    #[callback]
+    fn badge_issue_callback(
+        &self,
+        owner: ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.badge_token().set_token_id(token_id);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                self.badge_token().clear();
+
+                // Refund the issue cost
+                let refund = self.call_value().egld_value().clone_value();
+                self.send().direct_non_zero_egld(&owner, &refund);
+            }
+        }
+    }
+
+    // Views
+
+    #[view(getBounty)]
+    fn get_bounty(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+    ) -> Option<Bounty<Self::Api>> {
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        if bounties_mapper.is_empty() {
+            None
+        } else {
+            Some(self.with_repo_delegates(bounties_mapper.get()))
+        }
+    }
+
+    // Private
+
+    #[allow(clippy::too_many_arguments)]
+    fn create_bounty(
+        &self,
+        repo_owner: ManagedBuffer,
+        repo_url: ManagedBuffer,
+        issue_id: u64,
+        backer: ManagedAddress,
+        payment: EgldOrEsdtTokenPayment,
+        work_period: u64,
+        lockup: u64,
+        milestones: ManagedVec<Milestone<Self::Api>>,
+    ) {
+        self.require_not_paused();
+
+        let payment_token = payment.token_identifier;
+        let payment_amount = payment.amount;
+        require!(payment_amount > 0, "Payment amount must be greater than 0");
+
+        let mut milestones_amount = BigUint::zero();
+        for milestone in milestones.iter() {
+            milestones_amount += &milestone.amount;
+        }
+        require!(
+            milestones.is_empty() || milestones_amount == payment_amount,
+            "Milestone amounts must add up to the payment amount"
+        );
+
+        let bounties_mapper = self.bounties(&repo_owner, &repo_url, &issue_id);
+        if !bounties_mapper.is_empty() {
+            let previous_bounty = bounties_mapper.get();
+            require!(
+                previous_bounty.status == BountyStatus::Cancelled,
+                "Bounty already exists for this issue"
+            );
+
+            // Drop the cancelled bounty from the per-address indexes
+            self.bounties_by_proposer(&previous_bounty.proposer)
+                .swap_remove(&previous_bounty.id);
+            for solver in previous_bounty.solvers.iter() {
+                self.bounties_by_solver(&solver.solver_addr)
+                    .swap_remove(&previous_bounty.id);
+            }
+            self.release_approvals(&repo_owner, &repo_url, &issue_id)
+                .clear();
+            self.completion_claims(&repo_owner, &repo_url, &issue_id)
+                .clear();
+            self.payees(&repo_owner, &repo_url, &issue_id).clear();
+            self.disputes(&repo_owner, &repo_url, &issue_id).clear();
+            self.rejected_solvers(&repo_owner, &repo_url, &issue_id)
+                .clear();
+        }
+
+        let caller = self.blockchain().get_caller();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let bounty_id = self.index_bounty(&repo_owner, &repo_url, issue_id);
+
+        // A zero work period falls back to the contract-wide default
+        let work_period = if work_period == 0 {
+            self.default_work_period().get()
+        } else {
+            work_period
+        };
+
+        // A zero lockup falls back to the default, kept within the owner-set bounds
+        let min_lockup = self.min_lockup().get();
+        let max_lockup = self.max_lockup().get();
+        let lockup = if lockup == 0 {
+            DEFAULT_LOCKUP.clamp(min_lockup, max_lockup)
+        } else {
+            require!(
+                lockup >= min_lockup && lockup <= max_lockup,
+                "Lockup is outside the allowed bounds"
+            );
+            lockup
+        };
+
+        let bounty = Bounty {
+            id: bounty_id,
+            repo_url: repo_url.clone(),
+            issue_id,
+            repo_owner: repo_owner.clone(),
+            token: payment_token.clone(),
+            amount: payment_amount.clone(),
+            proposer: caller.clone(),
+            solvers: ManagedVec::new(),
+            status: BountyStatus::Funded,
+            created_at: current_timestamp,
+            milestones,
+            work_period,
+            lockup,
+            was_registered: false,
+            approvers: ManagedVec::new(),
+            approval_threshold: 0,
+            delegates: ManagedVec::new(),
+            registration_stake: BigUint::zero(),
+        };
+
+        bounties_mapper.set(&bounty);
+        self.track_open_bounty(&bounty);
+        self.bounties_by_proposer(&caller).insert(bounty_id);
+        self.contributions(&repo_owner, &repo_url, &issue_id)
+            .insert(backer, payment_amount.clone());
+
+        // Emit event for funding
+        self.fund_event(
+            repo_owner,
+            repo_url,
+            issue_id,
+            payment_token,
+            payment_amount,
+            caller,
+        );
+    }
+
+    fn call_payment(&self) -> EgldOrEsdtTokenPayment {
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        EgldOrEsdtTokenPayment::new(payment_token, 0, payment_amount)
+    }
+
+    /// Contributions recorded under the contract address were allocated from
+    /// the repo treasury, so their refunds go back into the pool.
+    fn refund_backer(&self, bounty: &Bounty<Self::Api>, backer: &ManagedAddress, amount: &BigUint) {
+        if backer == &self.blockchain().get_sc_address() {
+            self.add_to_treasury(&bounty.repo_owner, &bounty.repo_url, &bounty.token, amount);
+        } else {
+            self.send()
+                .direct_non_zero(backer, &bounty.token, 0, amount);
+        }
+    }
+
+    /// Backend signatures cover a type tag and the contract address before
+    /// the payload, so a signature only verifies for one kind of message on
+    /// one deployment.
+    fn signed_message<T: TopEncode>(&self, tag: &[u8], payload: &T) -> ManagedBuffer {
+        let mut encoded_payload = ManagedBuffer::new();
+        if payload.top_encode(&mut encoded_payload).is_err() {
+            sc_panic!("Failed to encode signed payload");
+        }
+
+        let mut message = ManagedBuffer::new_from_bytes(tag);
+        message.append(self.blockchain().get_sc_address().as_managed_buffer());
+        message.append(&encoded_payload);
+
+        message
+    }
+
+    fn refund_stakes(&self, bounty: &Bounty<Self::Api>) {
+        for solver in bounty.solvers.iter() {
+            self.send()
+                .direct_non_zero(&solver.solver_addr, &bounty.token, 0, &solver.stake);
+        }
+    }
+
+    /// Slashed stakes follow the funds: back to the treasury for allocated
+    /// bounties, to the proposer otherwise.
+    fn slash_stake(&self, bounty: &Bounty<Self::Api>, stake: &BigUint) {
+        let sc_address = self.blockchain().get_sc_address();
+        if self
+            .contributions(&bounty.repo_owner, &bounty.repo_url, &bounty.issue_id)
+            .contains_key(&sc_address)
+        {
+            self.add_to_treasury(&bounty.repo_owner, &bounty.repo_url, &bounty.token, stake);
+        } else {
+            self.send()
+                .direct_non_zero(&bounty.proposer, &bounty.token, 0, stake);
+        }
+    }
+
+    fn add_to_treasury(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        let mut treasury_mapper = self.treasury(repo_owner, repo_url);
+        let balance = treasury_mapper.get(token).unwrap_or_default() + amount;
+        treasury_mapper.insert(token.clone(), balance);
+    }
+
+    fn start_dispute(
+        &self,
+        mut bounty: Bounty<Self::Api>,
+        opened_by: ManagedAddress,
+        reason_hash: ManagedBuffer,
+    ) {
+        require!(!self.arbiters().is_empty(), "No arbiters are appointed");
+        require!(
+            self.disputes(&bounty.repo_owner, &bounty.repo_url, &bounty.issue_id)
+                .is_empty(),
+            "Bounty was already disputed"
+        );
+
+        bounty.status = BountyStatus::Disputed;
+        self.bounties(&bounty.repo_owner, &bounty.repo_url, &bounty.issue_id)
+            .set(&bounty);
+        // The arbiters decide instead, so the claim cannot be finalized later
+        self.completion_claims(&bounty.repo_owner, &bounty.repo_url, &bounty.issue_id)
+            .clear();
+        self.disputes(&bounty.repo_owner, &bounty.repo_url, &bounty.issue_id)
+            .set(Dispute {
+                opened_by: opened_by.clone(),
+                reason_hash: reason_hash.clone(),
+                opened_at: self.blockchain().get_block_timestamp(),
+            });
+
+        // Emit event for dispute
+        self.open_dispute_event(
+            bounty.repo_owner,
+            bounty.repo_url,
+            bounty.issue_id,
+            opened_by,
+            reason_hash,
+        );
+    }
+
+    fn pending_claim(&self, bounty: &Bounty<Self::Api>) -> Option<CompletionClaim<Self::Api>> {
+        let claim_mapper =
+            self.completion_claims(&bounty.repo_owner, &bounty.repo_url, &bounty.issue_id);
+        if claim_mapper.is_empty() {
+            None
+        } else {
+            Some(claim_mapper.get())
+        }
+    }
+
+    /// Drops the completion claim of a solver leaving the bounty, so it
+    /// cannot be finalized if they register again later.
+    fn clear_claim_of(&self, bounty: &Bounty<Self::Api>, solver_addr: &ManagedAddress) {
+        let claim_mapper =
+            self.completion_claims(&bounty.repo_owner, &bounty.repo_url, &bounty.issue_id);
+        if !claim_mapper.is_empty() && &claim_mapper.get().solver_addr == solver_addr {
+            claim_mapper.clear();
+        }
+    }
+
+    fn pay_solver(&self, bounty: Bounty<Self::Api>, solver: Solver<Self::Api>) {
+        require!(
+            bounty.milestones.is_empty(),
+            "Milestone bounties are released per milestone"
+        );
+
+        let fee = self.pay_with_fee(&solver.solver_addr, &bounty.token, &bounty.amount);
+        self.record_completion(&bounty, &solver.solver_addr, &bounty.amount);
+
+        // Update status
+        let mut updated_bounty = bounty.clone();
+        updated_bounty.status = BountyStatus::Completed;
+        self.bounties(&bounty.repo_owner, &bounty.repo_url, &bounty.issue_id)
+            .set(&updated_bounty);
+        self.untrack_open_bounty(&updated_bounty);
+        self.refund_stakes(&updated_bounty);
+        self.completion_claims(&bounty.repo_owner, &bounty.repo_url, &bounty.issue_id)
+            .clear();
+
+        // Emit event for completion
+        self.complete_event(
+            bounty.repo_owner,
+            bounty.repo_url,
+            bounty.issue_id,
+            solver.solver_addr,
+            solver.solver_github,
+            bounty.token,
+            bounty.amount,
+            fee,
+        );
+    }
+
+    fn index_bounty(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+        issue_id: u64,
+    ) -> u64 {
+        let bounty_id_mapper = self.bounty_id(repo_owner, repo_url, &issue_id);
+        if !bounty_id_mapper.is_empty() {
+            return bounty_id_mapper.get();
+        }
+
+        let bounty_id = self.bounty_keys().push(&BountyKey {
+            repo_owner: repo_owner.clone(),
+            repo_url: repo_url.clone(),
+            issue_id,
+        }) as u64;
+        bounty_id_mapper.set(bounty_id);
+
+        bounty_id
+    }
+
+    fn track_open_bounty(&self, bounty: &Bounty<Self::Api>) {
+        self.open_bounties_by_org(&bounty.repo_owner)
+            .insert(bounty.id);
+        self.open_bounties_by_repo(&bounty.repo_owner, &bounty.repo_url)
+            .insert(bounty.id);
+    }
+
+    fn untrack_open_bounty(&self, bounty: &Bounty<Self::Api>) {
+        self.open_bounties_by_org(&bounty.repo_owner)
+            .swap_remove(&bounty.id);
+        self.open_bounties_by_repo(&bounty.repo_owner, &bounty.repo_url)
+            .swap_remove(&bounty.id);
+    }
+
+    fn record_completion(
+        &self,
+        bounty: &Bounty<Self::Api>,
+        solver_addr: &ManagedAddress,
+        amount: &BigUint,
+    ) {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        self.solver_stats(solver_addr).update(|stats| {
+            if stats.completed_bounties == 0 {
+                stats.first_completed_at = current_timestamp;
+            }
+            stats.completed_bounties += 1;
+            stats.last_completed_at = current_timestamp;
+        });
+        self.ranked_solvers().insert(solver_addr.clone());
+        self.mint_badge(bounty, solver_addr, amount, current_timestamp);
+    }
+
+    fn add_payee(
+        &self,
+        bounty: &Bounty<Self::Api>,
+        solver_addr: &ManagedAddress,
+        amount: &BigUint,
+    ) {
+        let mut payees_mapper = self.payees(&bounty.repo_owner, &bounty.repo_url, &bounty.issue_id);
+        let paid = payees_mapper.get(solver_addr).unwrap_or_default() + amount;
+        payees_mapper.insert(solver_addr.clone(), paid);
+    }
+
+    /// Bounties settled in several payments count once for every solver paid
+    /// along the way, with the total each of them received.
+    fn record_payee_completions(&self, bounty: &Bounty<Self::Api>) {
+        let mut payees_mapper = self.payees(&bounty.repo_owner, &bounty.repo_url, &bounty.issue_id);
+        for (solver_addr, amount) in payees_mapper.iter() {
+            self.record_completion(bounty, &solver_addr, &amount);
+        }
+        payees_mapper.clear();
+    }
+
+    fn mint_badge(
+        &self,
+        bounty: &Bounty<Self::Api>,
+        solver_addr: &ManagedAddress,
+        amount: &BigUint,
+        completed_at: u64,
+    ) {
+        // Badges are only minted once the collection has been issued and made
+        // soulbound, so none can leave the solver's wallet
+        let badge_token_mapper = self.badge_token();
+        if !badge_token_mapper.get_token_state().is_set() {
+            return;
+        }
+        let badge_roles = self
+            .blockchain()
+            .get_esdt_local_roles(&badge_token_mapper.get_token_id());
+        if !badge_roles.has_role(&EsdtLocalRole::Transfer) {
+            return;
+        }
+
+        let attributes = BadgeAttributes {
+            repo_owner: bounty.repo_owner.clone(),
+            repo_url: bounty.repo_url.clone(),
+            issue_id: bounty.issue_id,
+            token: bounty.token.clone(),
+            amount: amount.clone(),
+            completed_at,
+        };
+        let badge =
+            badge_token_mapper.nft_create_and_send(solver_addr, BigUint::from(1u32), &attributes);
+        self.badge_bounty(badge.token_nonce).set(bounty.id);
+    }
+
+    fn pay_with_fee(
+        &self,
+        recipient: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) -> BigUint {
+        let fee = amount * self.fee_bps().get() / MAX_BASIS_POINTS;
+        if fee > 0 {
+            let mut collected_fees_mapper = self.collected_fees();
+            let collected_fees = collected_fees_mapper.get(token).unwrap_or_default() + &fee;
+            collected_fees_mapper.insert(token.clone(), collected_fees);
+        }
+
+        let payout = amount - &fee;
+        let mut earnings_mapper = self.earnings(recipient);
+        let earnings = earnings_mapper.get(token).unwrap_or_default() + &payout;
+        earnings_mapper.insert(token.clone(), earnings);
+
+        self.send().direct_non_zero(recipient, token, 0, &payout);
+
+        fee
+    }
+
+    fn take_from_treasury(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        require!(amount > &0, "Amount must be greater than 0");
+
+        let mut treasury_mapper = self.treasury(repo_owner, repo_url);
+        let balance = treasury_mapper.get(token).unwrap_or_default();
+        require!(&balance >= amount, "Insufficient treasury balance");
+
+        treasury_mapper.insert(token.clone(), balance - amount);
+    }
+
+    fn require_treasury_admin(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+        caller: &ManagedAddress,
+    ) {
+        let treasury_admin_mapper = self.treasury_admin(repo_owner, repo_url);
+        require!(!treasury_admin_mapper.is_empty(), "Treasury does not exist");
+        require!(
+            &treasury_admin_mapper.get() == caller,
+            "Only the treasury admin can manage the treasury"
+        );
+    }
+
+    /// Bounties allocated from a treasury answer to the treasury: the admin
+    /// can always act on them, and the allocating maintainer only while they
+    /// still belong to the treasury.
+    fn is_bounty_owner(&self, bounty: &Bounty<Self::Api>, address: &ManagedAddress) -> bool {
+        let sc_address = self.blockchain().get_sc_address();
+        if !self
+            .contributions(&bounty.repo_owner, &bounty.repo_url, &bounty.issue_id)
+            .contains_key(&sc_address)
+        {
+            return &bounty.proposer == address;
+        }
+
+        &self
+            .treasury_admin(&bounty.repo_owner, &bounty.repo_url)
+            .get()
+            == address
+            || (&bounty.proposer == address
+                && self.is_treasury_spender(&bounty.repo_owner, &bounty.repo_url, address))
+    }
+
+    fn is_proposer_or_delegate(
+        &self,
+        bounty: &Bounty<Self::Api>,
+        address: &ManagedAddress,
+    ) -> bool {
+        if self.is_bounty_owner(bounty, address) {
+            return true;
+        }
+
+        // Delegates act for the proposer, so they lose access along with them
+        self.is_bounty_owner(bounty, &bounty.proposer)
+            && (bounty.delegates.contains(address)
+                || self
+                    .repo_delegates(&bounty.proposer, &bounty.repo_owner, &bounty.repo_url)
+                    .contains(address))
+    }
+
+    fn is_treasury_spender(
+        &self,
+        repo_owner: &ManagedBuffer,
+        repo_url: &ManagedBuffer,
+        address: &ManagedAddress,
+    ) -> bool {
+        &self.treasury_admin(repo_owner, repo_url).get() == address
+            || self
+                .treasury_maintainers(repo_owner, repo_url)
+                .contains(address)
+    }
+
+    fn require_linked_solver(
+        &self,
+        bounty: &Bounty<Self::Api>,
+        solver_addr: &ManagedAddress,
+        solver_github: &ManagedBuffer,
+    ) -> Solver<Self::Api> {
+        let solver_index = self.require_solver_index(&bounty.solvers, solver_addr);
+        let solver = bounty.solvers.get(solver_index);
+        require!(
+            &solver.solver_github == solver_github
+                && &self.solvers(solver_addr).get() == solver_github,
+            "GitHub login does not match the linked identity"
+        );
+
+        solver
+    }
+
+    fn require_solver_index(
+        &self,
+        solvers: &ManagedVec<Solver<Self::Api>>,
+        solver_addr: &ManagedAddress,
+    ) -> usize {
+        match solvers
+            .iter()
+            .position(|solver| &solver.solver_addr == solver_addr)
+        {
+            Some(index) => index,
+            None => sc_panic!("Solver is not registered"),
         }
     }
 }
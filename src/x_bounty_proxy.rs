@@ -0,0 +1,1757 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct XBountyProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for XBountyProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = XBountyProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        XBountyProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct XBountyProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> XBountyProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn init(
+        self,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> XBountyProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Bounties are stored in a layout that older deployments cannot decode, 
+    /// so only contracts deployed with the current layout can be upgraded. 
+    /// Bump `STORAGE_VERSION` whenever a stored struct changes shape. 
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> XBountyProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn fund<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<OptionalValue<u64>>,
+        Arg4: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        opt_work_period: Arg3,
+        opt_lockup: Arg4,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fund")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&opt_work_period)
+            .argument(&opt_lockup)
+            .original_result()
+    }
+
+    pub fn fund_milestones<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<BigUint<Env::Api>, ManagedBuffer<Env::Api>>>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        work_period: Arg3,
+        lockup: Arg4,
+        milestones: Arg5,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fundMilestones")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&work_period)
+            .argument(&lockup)
+            .argument(&milestones)
+            .original_result()
+    }
+
+    pub fn fund_with_approvers<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<usize>,
+        Arg4: ProxyArg<ManagedVec<Env::Api, ManagedAddress<Env::Api>>>,
+        Arg5: ProxyArg<OptionalValue<u64>>,
+        Arg6: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        approval_threshold: Arg3,
+        approvers: Arg4,
+        opt_work_period: Arg5,
+        opt_lockup: Arg6,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fundWithApprovers")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&approval_threshold)
+            .argument(&approvers)
+            .argument(&opt_work_period)
+            .argument(&opt_lockup)
+            .original_result()
+    }
+
+    pub fn create_treasury<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        admin: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createTreasury")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&admin)
+            .original_result()
+    }
+
+    pub fn transfer_treasury_admin<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        new_admin: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("transferTreasuryAdmin")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&new_admin)
+            .original_result()
+    }
+
+    pub fn deposit_to_treasury<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("depositToTreasury")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .original_result()
+    }
+
+    pub fn withdraw_from_treasury<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        token: Arg2,
+        amount: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawFromTreasury")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&token)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn add_treasury_maintainer<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        maintainer: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addTreasuryMaintainer")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&maintainer)
+            .original_result()
+    }
+
+    pub fn remove_treasury_maintainer<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        maintainer: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeTreasuryMaintainer")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&maintainer)
+            .original_result()
+    }
+
+    pub fn allocate_bounty<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
+        Arg5: ProxyArg<OptionalValue<u64>>,
+        Arg6: ProxyArg<OptionalValue<u64>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        token: Arg3,
+        amount: Arg4,
+        opt_work_period: Arg5,
+        opt_lockup: Arg6,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("allocateBounty")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&token)
+            .argument(&amount)
+            .argument(&opt_work_period)
+            .argument(&opt_lockup)
+            .original_result()
+    }
+
+    pub fn top_up<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("topUp")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    pub fn cancel_bounty<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelBounty")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    pub fn register<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("register")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    pub fn set_registration_stake<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        registration_stake: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRegistrationStake")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&registration_stake)
+            .original_result()
+    }
+
+    pub fn link_github<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        solver_github: Arg0,
+        signature: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("linkGithub")
+            .argument(&solver_github)
+            .argument(&signature)
+            .original_result()
+    }
+
+    /// Lets the owner release a login from a lost or wrongly linked wallet so 
+    /// it can be linked again. Registrations of the old wallet stay listed 
+    /// but can no longer be paid by the oracle, attestations or claims. 
+    pub fn unlink_github<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        solver_addr: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unlinkGithub")
+            .argument(&solver_addr)
+            .original_result()
+    }
+
+    pub fn withdraw_registration<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawRegistration")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    pub fn reject_solver<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg4: ProxyArg<bool>,
+        Arg5: ProxyArg<OptionalValue<ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        solver_addr: Arg3,
+        is_spam: Arg4,
+        opt_reason_hash: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("rejectSolver")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&solver_addr)
+            .argument(&is_spam)
+            .argument(&opt_reason_hash)
+            .original_result()
+    }
+
+    pub fn expire_registrations<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("expireRegistrations")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    pub fn release_bounty<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        solver_addr: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("releaseBounty")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&solver_addr)
+            .original_result()
+    }
+
+    pub fn add_delegate<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        delegate: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addDelegate")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&delegate)
+            .original_result()
+    }
+
+    pub fn remove_delegate<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        delegate: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeDelegate")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&delegate)
+            .original_result()
+    }
+
+    pub fn add_repo_delegate<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        delegate: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addRepoDelegate")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&delegate)
+            .original_result()
+    }
+
+    pub fn remove_repo_delegate<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        delegate: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeRepoDelegate")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&delegate)
+            .original_result()
+    }
+
+    pub fn revoke_approval<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeApproval")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    pub fn release_milestone<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        solver_addr: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("releaseMilestone")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&solver_addr)
+            .original_result()
+    }
+
+    pub fn release_split<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg4: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, u64>>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        dust_recipient: Arg3,
+        shares: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("releaseSplit")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&dust_recipient)
+            .argument(&shares)
+            .original_result()
+    }
+
+    pub fn release_by_oracle<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg5: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        pr_number: Arg3,
+        solver_github: Arg4,
+        solver_addr: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("releaseByOracle")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&pr_number)
+            .argument(&solver_github)
+            .argument(&solver_addr)
+            .original_result()
+    }
+
+    pub fn claim_with_attestation<
+        Arg0: ProxyArg<MergeAttestation<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        attestation: Arg0,
+        signature: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimWithAttestation")
+            .argument(&attestation)
+            .argument(&signature)
+            .original_result()
+    }
+
+    pub fn open_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        reason_hash: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("openDispute")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&reason_hash)
+            .original_result()
+    }
+
+    pub fn submit_completion<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        pr_reference: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("submitCompletion")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&pr_reference)
+            .original_result()
+    }
+
+    pub fn object_completion<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        reason_hash: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("objectCompletion")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&reason_hash)
+            .original_result()
+    }
+
+    pub fn finalize<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("finalize")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    /// Unfreezes a dispute the arbiters left open for too long. The bounty 
+    /// goes back to `Registered` and keeps its dispute record, so no new 
+    /// dispute or completion claim can stall cancellation again. 
+    pub fn expire_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("expireDispute")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    pub fn resolve_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+        solver_addr: Arg3,
+        solver_share_bps: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resolveDispute")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .argument(&solver_addr)
+            .argument(&solver_share_bps)
+            .original_result()
+    }
+
+    pub fn add_oracle<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        oracle: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addOracle")
+            .argument(&oracle)
+            .original_result()
+    }
+
+    pub fn remove_oracle<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        oracle: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeOracle")
+            .argument(&oracle)
+            .original_result()
+    }
+
+    pub fn add_arbiter<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        arbiter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addArbiter")
+            .argument(&arbiter)
+            .original_result()
+    }
+
+    pub fn remove_arbiter<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        arbiter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeArbiter")
+            .argument(&arbiter)
+            .original_result()
+    }
+
+    pub fn set_max_solvers<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        max_solvers: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxSolvers")
+            .argument(&max_solvers)
+            .original_result()
+    }
+
+    pub fn set_max_backers<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        max_backers: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxBackers")
+            .argument(&max_backers)
+            .original_result()
+    }
+
+    pub fn set_default_work_period<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        work_period: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDefaultWorkPeriod")
+            .argument(&work_period)
+            .original_result()
+    }
+
+    pub fn set_review_period<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        review_period: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setReviewPeriod")
+            .argument(&review_period)
+            .original_result()
+    }
+
+    pub fn set_dispute_timeout<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        dispute_timeout: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setDisputeTimeout")
+            .argument(&dispute_timeout)
+            .original_result()
+    }
+
+    pub fn set_lockup_bounds<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        min_lockup: Arg0,
+        max_lockup: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLockupBounds")
+            .argument(&min_lockup)
+            .argument(&max_lockup)
+            .original_result()
+    }
+
+    pub fn set_fee<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        fee_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setFee")
+            .argument(&fee_bps)
+            .original_result()
+    }
+
+    pub fn withdraw_fees<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawFees")
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn set_attestation_signer<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        public_key: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setAttestationSigner")
+            .argument(&public_key)
+            .original_result()
+    }
+
+    pub fn issue_badge_collection<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        display_name: Arg0,
+        ticker: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("issueBadgeCollection")
+            .argument(&display_name)
+            .argument(&ticker)
+            .original_result()
+    }
+
+    /// Holding the transfer role restricts badge transfers to this contract, 
+    /// which makes the badges soulbound once they reach the solver. No badge 
+    /// is minted before this role is set. 
+    pub fn set_badge_transfer_role(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setBadgeTransferRole")
+            .original_result()
+    }
+
+    pub fn get_bounty<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<Bounty<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBounty")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
+    pub fn bounties<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Bounty<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBountyIds")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    pub fn bounty_id<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBountyId")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    pub fn contributions<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContributions")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    pub fn rejected_solvers<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRejectedSolvers")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    pub fn repo_delegates<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        proposer: Arg0,
+        repo_owner: Arg1,
+        repo_url: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRepoDelegates")
+            .argument(&proposer)
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .original_result()
+    }
+
+    pub fn release_approvals<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, ManagedAddress<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReleaseApprovals")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    pub fn treasury<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTreasury")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .original_result()
+    }
+
+    pub fn treasury_admin<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTreasuryAdmin")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .original_result()
+    }
+
+    pub fn treasury_maintainers<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTreasuryMaintainers")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .original_result()
+    }
+
+    pub fn max_solvers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxSolvers")
+            .original_result()
+    }
+
+    pub fn max_backers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxBackers")
+            .original_result()
+    }
+
+    pub fn default_work_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDefaultWorkPeriod")
+            .original_result()
+    }
+
+    pub fn min_lockup(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinLockup")
+            .original_result()
+    }
+
+    pub fn max_lockup(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxLockup")
+            .original_result()
+    }
+
+    pub fn solvers<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        solver_addr: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSolverGithub")
+            .argument(&solver_addr)
+            .original_result()
+    }
+
+    pub fn solver_address<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        solver_github: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSolverAddress")
+            .argument(&solver_github)
+            .original_result()
+    }
+
+    pub fn oracles(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOracles")
+            .original_result()
+    }
+
+    pub fn arbiters(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getArbiters")
+            .original_result()
+    }
+
+    pub fn disputes<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Dispute<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDispute")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+
+    pub fn dispute_timeout(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDisputeTimeout")
+            .original_result()
+    }
+
+    pub fn review_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getReviewPeriod")
+            .original_result()
+    }
+
+    pub fn attestation_signer(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedBuffer<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAttestationSigner")
+            .original_result()
+    }
+
+    pub fn storage_version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStorageVersion")
+            .original_result()
+    }
+
+    pub fn fee_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFee")
+            .original_result()
+    }
+
+    pub fn collected_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCollectedFees")
+            .original_result()
+    }
+
+    pub fn badge_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBadgeToken")
+            .original_result()
+    }
+
+    pub fn get_bounty_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBountyCount")
+            .original_result()
+    }
+
+    pub fn get_all_bounties<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        from: Arg0,
+        size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Bounty<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAllBounties")
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// Pages over bounty ids rather than over matches, so a page never loads 
+    /// more than `size` bounties and may hold fewer matches than that. 
+    pub fn get_bounties_by_status<
+        Arg0: ProxyArg<BountyStatus>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        status: Arg0,
+        from: Arg1,
+        size: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Bounty<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBountiesByStatus")
+            .argument(&status)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    pub fn get_open_bounties_by_org<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Bounty<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOpenBountiesByOrg")
+            .argument(&repo_owner)
+            .original_result()
+    }
+
+    pub fn get_open_bounties_by_repo<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Bounty<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOpenBountiesByRepo")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .original_result()
+    }
+
+    pub fn get_repo_escrow<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, TokenAmount<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRepoEscrow")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .original_result()
+    }
+
+    pub fn get_bounties_by_proposer<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Bounty<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBountiesByProposer")
+            .argument(&proposer)
+            .original_result()
+    }
+
+    pub fn get_bounties_by_solver<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        solver_addr: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, Bounty<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBountiesBySolver")
+            .argument(&solver_addr)
+            .original_result()
+    }
+
+    pub fn get_proposer_escrow<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, TokenAmount<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposerEscrow")
+            .argument(&proposer)
+            .original_result()
+    }
+
+    pub fn get_solver_earnings<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        solver_addr: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, TokenAmount<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSolverEarnings")
+            .argument(&solver_addr)
+            .original_result()
+    }
+
+    pub fn get_solver_stats<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        solver_addr: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, SolverProfile<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSolverStats")
+            .argument(&solver_addr)
+            .original_result()
+    }
+
+    pub fn get_leaderboard<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        token: Arg0,
+        size: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, LeaderboardEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLeaderboard")
+            .argument(&token)
+            .argument(&size)
+            .original_result()
+    }
+
+    pub fn get_badge_bounty<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        badge_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<Bounty<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBadgeBounty")
+            .argument(&badge_nonce)
+            .original_result()
+    }
+
+    pub fn get_completion_claim<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        repo_owner: Arg0,
+        repo_url: Arg1,
+        issue_id: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Option<CompletionClaim<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCompletionClaim")
+            .argument(&repo_owner)
+            .argument(&repo_url)
+            .argument(&issue_id)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct MergeAttestation<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub repo_owner: ManagedBuffer<Api>,
+    pub repo_url: ManagedBuffer<Api>,
+    pub issue_id: u64,
+    pub pr_number: u64,
+    pub solver_github: ManagedBuffer<Api>,
+    pub solver_addr: ManagedAddress<Api>,
+    pub nonce: u64,
+    pub expiry: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Bounty<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: u64,
+    pub repo_url: ManagedBuffer<Api>,
+    pub issue_id: u64,
+    pub repo_owner: ManagedBuffer<Api>,
+    pub token: EgldOrEsdtTokenIdentifier<Api>,
+    pub amount: BigUint<Api>,
+    pub proposer: ManagedAddress<Api>,
+    pub solvers: ManagedVec<Api, Solver<Api>>,
+    pub status: BountyStatus,
+    pub created_at: u64,
+    pub milestones: ManagedVec<Api, Milestone<Api>>,
+    pub work_period: u64,
+    pub lockup: u64,
+    pub was_registered: bool,
+    pub approvers: ManagedVec<Api, ManagedAddress<Api>>,
+    pub approval_threshold: usize,
+    pub delegates: ManagedVec<Api, ManagedAddress<Api>>,
+    pub registration_stake: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, ManagedVecItem, PartialEq,
+)]
+pub struct Solver<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub solver_addr: ManagedAddress<Api>,
+    pub solver_github: ManagedBuffer<Api>,
+    pub deadline: u64,
+    pub stake: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Debug)]
+pub enum BountyStatus {
+    Funded,
+    Registered,
+    Completed,
+    Cancelled,
+    Disputed,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, ManagedVecItem)]
+pub struct Milestone<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub amount: BigUint<Api>,
+    pub description_hash: ManagedBuffer<Api>,
+    pub status: MilestoneStatus,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, ManagedVecItem, PartialEq,
+)]
+pub enum MilestoneStatus {
+    Pending,
+    Paid,
+    Cancelled,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct Dispute<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub opened_by: ManagedAddress<Api>,
+    pub reason_hash: ManagedBuffer<Api>,
+    pub opened_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, ManagedVecItem)]
+pub struct TokenAmount<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token: EgldOrEsdtTokenIdentifier<Api>,
+    pub amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct SolverProfile<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub solver_addr: ManagedAddress<Api>,
+    pub completed_bounties: u64,
+    pub uncompleted_registrations: u64,
+    pub first_completed_at: u64,
+    pub last_completed_at: u64,
+    pub earnings: ManagedVec<Api, TokenAmount<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug, ManagedVecItem)]
+pub struct LeaderboardEntry<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub solver_addr: ManagedAddress<Api>,
+    pub earned: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Debug)]
+pub struct CompletionClaim<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub solver_addr: ManagedAddress<Api>,
+    pub pr_reference: ManagedBuffer<Api>,
+    pub submitted_at: u64,
+}
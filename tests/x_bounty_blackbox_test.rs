@@ -0,0 +1,2285 @@
+use ed25519_dalek::{Signer, SigningKey};
+use multiversx_sc_scenario::imports::*;
+
+use x_bounty::x_bounty_proxy;
+use x_bounty::DEFAULT_DISPUTE_TIMEOUT;
+use x_bounty::DEFAULT_LOCKUP;
+use x_bounty::DEFAULT_REVIEW_PERIOD;
+use x_bounty::DEFAULT_WORK_PERIOD;
+use x_bounty::IDENTITY_PROOF_TAG;
+use x_bounty::MAX_FEE_BPS;
+use x_bounty::MERGE_ATTESTATION_TAG;
+
+const CODE_PATH: MxscPath = MxscPath::new("output/x_bounty.mxsc.json");
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const PROPOSER_ADDRESS: TestAddress = TestAddress::new("proposer");
+const SOLVER_ADDRESS: TestAddress = TestAddress::new("solver");
+const SECOND_SOLVER_ADDRESS: TestAddress = TestAddress::new("second-solver");
+const ORACLE_ADDRESS: TestAddress = TestAddress::new("oracle");
+const ARBITER_ADDRESS: TestAddress = TestAddress::new("arbiter");
+const X_BOUNTY_ADDRESS: TestSCAddress = TestSCAddress::new("x-bounty");
+
+const REPO_OWNER: &str = "multiversx";
+const REPO_URL: &str = "mx-contracts-rs";
+const ISSUE_ID: u64 = 133;
+const SOLVER_GITHUB: &str = "costincarabas";
+const SECOND_SOLVER_GITHUB: &str = "second-solver";
+const PR_NUMBER: u64 = 134;
+const BOUNTY_AMOUNT: u64 = 1_000;
+const INITIAL_BALANCE: u64 = 10_000;
+const SIGNER_SECRET_KEY: [u8; 32] = [7u8; 32];
+const BOUNTY_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("USDC-123456");
+const BADGE_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("BADGE-123456");
+const BADGE_ISSUE_COST: u64 = 50;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.set_current_dir_from_workspace("");
+    blockchain.register_contract(CODE_PATH, x_bounty::ContractBuilder);
+    blockchain
+}
+
+struct XBountyTestState {
+    world: ScenarioWorld,
+}
+
+impl XBountyTestState {
+    fn new() -> Self {
+        let mut world = world();
+
+        world.account(OWNER_ADDRESS).nonce(1);
+        world
+            .account(PROPOSER_ADDRESS)
+            .nonce(1)
+            .balance(INITIAL_BALANCE)
+            .esdt_balance(BOUNTY_TOKEN_ID, INITIAL_BALANCE);
+        world.account(SOLVER_ADDRESS).nonce(1);
+        world.account(SECOND_SOLVER_ADDRESS).nonce(1);
+        world.account(ORACLE_ADDRESS).nonce(1);
+        world.account(ARBITER_ADDRESS).nonce(1);
+
+        world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .init()
+            .code(CODE_PATH)
+            .new_address(X_BOUNTY_ADDRESS)
+            .run();
+
+        let mut state = Self { world };
+        state.set_attestation_signer();
+        state.link_github(SOLVER_ADDRESS, SOLVER_GITHUB);
+        state.link_github(SECOND_SOLVER_ADDRESS, SECOND_SOLVER_GITHUB);
+
+        state
+    }
+
+    fn set_attestation_signer(&mut self) {
+        let signing_key = SigningKey::from_bytes(&SIGNER_SECRET_KEY);
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .set_attestation_signer(ManagedBuffer::from(signing_key.verifying_key().as_bytes()))
+            .run();
+    }
+
+    fn link_github(&mut self, solver: TestAddress, solver_github: &str) {
+        // Type tag and contract address, then the nested-encoded
+        // (solver_addr, solver_github) pair
+        let mut message = IDENTITY_PROOF_TAG.to_vec();
+        message.extend_from_slice(X_BOUNTY_ADDRESS.to_address().as_bytes());
+        message.extend_from_slice(solver.to_address().as_bytes());
+        message.extend_from_slice(&(solver_github.len() as u32).to_be_bytes());
+        message.extend_from_slice(solver_github.as_bytes());
+        let signature = SigningKey::from_bytes(&SIGNER_SECRET_KEY).sign(&message);
+
+        self.world
+            .tx()
+            .from(solver)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .link_github(
+                solver_github,
+                ManagedBuffer::from(&signature.to_bytes()[..]),
+            )
+            .run();
+    }
+
+    fn merge_attestation(
+        &self,
+        nonce: u64,
+        expiry: u64,
+    ) -> x_bounty_proxy::MergeAttestation<StaticApi> {
+        x_bounty_proxy::MergeAttestation {
+            repo_owner: ManagedBuffer::from(REPO_OWNER),
+            repo_url: ManagedBuffer::from(REPO_URL),
+            issue_id: ISSUE_ID,
+            pr_number: PR_NUMBER,
+            solver_github: ManagedBuffer::from(SOLVER_GITHUB),
+            solver_addr: SOLVER_ADDRESS.to_managed_address(),
+            nonce,
+            expiry,
+        }
+    }
+
+    fn sign_attestation(
+        &self,
+        attestation: &x_bounty_proxy::MergeAttestation<StaticApi>,
+        contract: TestSCAddress,
+        secret_key: &[u8; 32],
+    ) -> ManagedBuffer<StaticApi> {
+        // Type tag and contract address, then the top-encoded attestation
+        let mut message = MERGE_ATTESTATION_TAG.to_vec();
+        message.extend_from_slice(contract.to_address().as_bytes());
+        message.extend_from_slice(&top_encode_to_vec_u8_or_panic(attestation));
+        let signature = SigningKey::from_bytes(secret_key).sign(&message);
+
+        ManagedBuffer::from(&signature.to_bytes()[..])
+    }
+
+    fn claim_with_attestation(
+        &mut self,
+        attestation: x_bounty_proxy::MergeAttestation<StaticApi>,
+        signature: ManagedBuffer<StaticApi>,
+    ) {
+        self.world
+            .tx()
+            .from(ORACLE_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .claim_with_attestation(attestation, signature)
+            .run();
+    }
+
+    fn claim_with_attestation_expect_err(
+        &mut self,
+        attestation: x_bounty_proxy::MergeAttestation<StaticApi>,
+        signature: ManagedBuffer<StaticApi>,
+        err_code: u64,
+        err_message: &str,
+    ) {
+        self.world
+            .tx()
+            .from(ORACLE_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .claim_with_attestation(attestation, signature)
+            .returns(ExpectError(err_code, err_message))
+            .run();
+    }
+
+    fn unlink_github(&mut self, solver: TestAddress) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .unlink_github(solver)
+            .run();
+    }
+
+    fn fund(&mut self, amount: u64) {
+        self.fund_bounty(REPO_URL, ISSUE_ID, None, amount, 0, None);
+    }
+
+    fn fund_issue(&mut self, issue_id: u64, amount: u64) {
+        self.fund_bounty(REPO_URL, issue_id, None, amount, 0, None);
+    }
+
+    /// Funds through `fund`, in EGLD unless a token is given. A zero lockup
+    /// keeps the default.
+    fn fund_bounty(
+        &mut self,
+        repo_url: &str,
+        issue_id: u64,
+        token: Option<TestTokenIdentifier>,
+        amount: u64,
+        lockup: u64,
+        err_message: Option<&str>,
+    ) {
+        let token = match token {
+            Some(token) => EgldOrEsdtTokenIdentifier::esdt(token.to_token_identifier()),
+            None => EgldOrEsdtTokenIdentifier::egld(),
+        };
+        let payment = EgldOrEsdtTokenPayment::new(token, 0, BigUint::from(amount));
+
+        let tx = self
+            .world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .fund(
+                REPO_OWNER,
+                repo_url,
+                issue_id,
+                OptionalValue::Some(0u64),
+                OptionalValue::Some(lockup),
+            )
+            .payment(payment);
+        match err_message {
+            Some(err_message) => tx.returns(ExpectError(4, err_message)).run(),
+            None => tx.run(),
+        }
+    }
+
+    fn fund_with_approvers(&mut self, amount: u64, approval_threshold: usize, lockup: u64) {
+        let mut approvers = ManagedVec::new();
+        approvers.push(OWNER_ADDRESS.to_managed_address());
+        approvers.push(ORACLE_ADDRESS.to_managed_address());
+        approvers.push(ARBITER_ADDRESS.to_managed_address());
+
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .fund_with_approvers(
+                REPO_OWNER,
+                REPO_URL,
+                ISSUE_ID,
+                approval_threshold,
+                approvers,
+                OptionalValue::Some(0u64),
+                OptionalValue::Some(lockup),
+            )
+            .egld(amount)
+            .run();
+    }
+
+    fn fund_milestones(&mut self, amounts: &[u64]) {
+        let mut milestones = MultiValueEncoded::new();
+        let mut total_amount = 0u64;
+        for amount in amounts {
+            milestones.push((BigUint::from(*amount), ManagedBuffer::from("milestone")).into());
+            total_amount += amount;
+        }
+
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .fund_milestones(REPO_OWNER, REPO_URL, ISSUE_ID, 0u64, 0u64, milestones)
+            .egld(total_amount)
+            .run();
+    }
+
+    fn top_up(&mut self, backer: TestAddress, amount: u64) {
+        self.world.set_egld_balance(backer, amount);
+        self.world
+            .tx()
+            .from(backer)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .top_up(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .egld(amount)
+            .run();
+    }
+
+    fn top_up_expect_err(&mut self, backer: TestAddress, amount: u64, err_message: &str) {
+        self.world.set_egld_balance(backer, amount);
+        self.world
+            .tx()
+            .from(backer)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .top_up(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .egld(amount)
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn set_max_backers(&mut self, max_backers: usize) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .set_max_backers(max_backers)
+            .run();
+    }
+
+    fn register(&mut self) {
+        self.register_solver(SOLVER_ADDRESS);
+    }
+
+    fn register_solver(&mut self, solver: TestAddress) {
+        self.world
+            .tx()
+            .from(solver)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .register(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .run();
+    }
+
+    fn register_on_issue(&mut self, issue_id: u64) {
+        self.world
+            .tx()
+            .from(SOLVER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .register(REPO_OWNER, REPO_URL, issue_id)
+            .run();
+    }
+
+    fn register_expect_err(&mut self, solver: TestAddress, err_message: &str) {
+        self.world
+            .tx()
+            .from(solver)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .register(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn set_max_solvers(&mut self, max_solvers: usize) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .set_max_solvers(max_solvers)
+            .run();
+    }
+
+    fn release_bounty(&mut self) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .release_bounty(REPO_OWNER, REPO_URL, ISSUE_ID, SOLVER_ADDRESS)
+            .run();
+    }
+
+    fn release_issue(&mut self, issue_id: u64) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .release_bounty(REPO_OWNER, REPO_URL, issue_id, SOLVER_ADDRESS)
+            .run();
+    }
+
+    fn release_bounty_expect_err(&mut self, solver: TestAddress, err_message: &str) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .release_bounty(REPO_OWNER, REPO_URL, ISSUE_ID, solver)
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn add_delegate(&mut self, delegate: TestAddress) {
+        self.add_delegate_from(PROPOSER_ADDRESS, delegate);
+    }
+
+    fn add_delegate_from(&mut self, proposer: TestAddress, delegate: TestAddress) {
+        self.world
+            .tx()
+            .from(proposer)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .add_delegate(REPO_OWNER, REPO_URL, ISSUE_ID, delegate)
+            .run();
+    }
+
+    fn remove_delegate(&mut self, delegate: TestAddress) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .remove_delegate(REPO_OWNER, REPO_URL, ISSUE_ID, delegate)
+            .run();
+    }
+
+    fn add_repo_delegate(&mut self, delegate: TestAddress) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .add_repo_delegate(REPO_OWNER, REPO_URL, delegate)
+            .run();
+    }
+
+    fn check_delegates(&mut self, delegates: &[TestAddress]) {
+        let bounty = self
+            .world
+            .query()
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .get_bounty(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .returns(ReturnsResult)
+            .run()
+            .unwrap();
+
+        let expected = delegates
+            .iter()
+            .map(|delegate| delegate.to_managed_address())
+            .collect::<Vec<_>>();
+        assert_eq!(bounty.delegates.into_iter().collect::<Vec<_>>(), expected);
+    }
+
+    fn release_bounty_from_expect_err(&mut self, from: TestAddress, err_message: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .release_bounty(REPO_OWNER, REPO_URL, ISSUE_ID, SOLVER_ADDRESS)
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn release_bounty_from(&mut self, from: TestAddress) {
+        self.world
+            .tx()
+            .from(from)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .release_bounty(REPO_OWNER, REPO_URL, ISSUE_ID, SOLVER_ADDRESS)
+            .run();
+    }
+
+    fn revoke_approval(&mut self, approver: TestAddress) {
+        self.world
+            .tx()
+            .from(approver)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .revoke_approval(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .run();
+    }
+
+    fn release_milestone(&mut self) {
+        self.release_milestone_to(SOLVER_ADDRESS);
+    }
+
+    fn release_milestone_to(&mut self, solver: TestAddress) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .release_milestone(REPO_OWNER, REPO_URL, ISSUE_ID, solver)
+            .run();
+    }
+
+    fn release_split(&mut self, shares: &[(TestAddress, u64)]) {
+        let mut shares_arg = MultiValueEncoded::new();
+        for (solver, share_bps) in shares {
+            shares_arg.push((solver.to_managed_address(), *share_bps).into());
+        }
+
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .release_split(REPO_OWNER, REPO_URL, ISSUE_ID, PROPOSER_ADDRESS, shares_arg)
+            .run();
+    }
+
+    fn release_split_expect_err(&mut self, shares: &[(TestAddress, u64)], err_message: &str) {
+        let mut shares_arg = MultiValueEncoded::new();
+        for (solver, share_bps) in shares {
+            shares_arg.push((solver.to_managed_address(), *share_bps).into());
+        }
+
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .release_split(REPO_OWNER, REPO_URL, ISSUE_ID, PROPOSER_ADDRESS, shares_arg)
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn set_fee(&mut self, fee_bps: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .set_fee(fee_bps)
+            .run();
+    }
+
+    fn set_fee_expect_err(&mut self, fee_bps: u64, err_message: &str) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .set_fee(fee_bps)
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn withdraw_fees(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .withdraw_fees(EgldOrEsdtTokenIdentifier::egld())
+            .run();
+    }
+
+    fn pause(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .pause()
+            .run();
+    }
+
+    fn add_oracle(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .add_oracle(ORACLE_ADDRESS)
+            .run();
+    }
+
+    fn release_by_oracle(&mut self) {
+        self.world
+            .tx()
+            .from(ORACLE_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .release_by_oracle(
+                REPO_OWNER,
+                REPO_URL,
+                ISSUE_ID,
+                PR_NUMBER,
+                SOLVER_GITHUB,
+                SOLVER_ADDRESS,
+            )
+            .run();
+    }
+
+    fn release_by_oracle_expect_err(&mut self, from: TestAddress, err_message: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .release_by_oracle(
+                REPO_OWNER,
+                REPO_URL,
+                ISSUE_ID,
+                PR_NUMBER,
+                SOLVER_GITHUB,
+                SOLVER_ADDRESS,
+            )
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn cancel_bounty(&mut self) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .cancel_bounty(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .run();
+    }
+
+    fn cancel_bounty_expect_err(&mut self, err_message: &str) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .cancel_bounty(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn cancel_issue(&mut self, issue_id: u64) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .cancel_bounty(REPO_OWNER, REPO_URL, issue_id)
+            .run();
+    }
+
+    fn check_all_bounties(&mut self, from: usize, size: usize, issue_ids: &[u64]) {
+        let bounties = self
+            .world
+            .query()
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .get_all_bounties(from, size)
+            .returns(ReturnsResult)
+            .run();
+
+        let found: Vec<u64> = bounties.into_iter().map(|bounty| bounty.issue_id).collect();
+        assert_eq!(found, issue_ids);
+    }
+
+    fn check_bounties_by_status(
+        &mut self,
+        status: x_bounty_proxy::BountyStatus,
+        from: usize,
+        size: usize,
+        issue_ids: &[u64],
+    ) {
+        let bounties = self
+            .world
+            .query()
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .get_bounties_by_status(status, from, size)
+            .returns(ReturnsResult)
+            .run();
+
+        let found: Vec<u64> = bounties.into_iter().map(|bounty| bounty.issue_id).collect();
+        assert_eq!(found, issue_ids);
+    }
+
+    fn check_open_bounties_by_repo(&mut self, bounty_ids: &[u64]) {
+        let bounties = self
+            .world
+            .query()
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .get_open_bounties_by_repo(REPO_OWNER, REPO_URL)
+            .returns(ReturnsResult)
+            .run();
+
+        let mut found: Vec<u64> = bounties.into_iter().map(|bounty| bounty.id).collect();
+        found.sort();
+        assert_eq!(found, bounty_ids);
+    }
+
+    fn check_open_bounties_by_org(&mut self, bounty_ids: &[u64]) {
+        let bounties = self
+            .world
+            .query()
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .get_open_bounties_by_org(REPO_OWNER)
+            .returns(ReturnsResult)
+            .run();
+
+        let mut found: Vec<u64> = bounties.into_iter().map(|bounty| bounty.id).collect();
+        found.sort();
+        assert_eq!(found, bounty_ids);
+    }
+
+    fn check_repo_escrow(&mut self, amounts: &[u64]) {
+        let escrow = self
+            .world
+            .query()
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .get_repo_escrow(REPO_OWNER, REPO_URL)
+            .returns(ReturnsResult)
+            .run();
+
+        let found: Vec<u64> = escrow
+            .iter()
+            .map(|total| total.amount.to_u64().unwrap())
+            .collect();
+        assert_eq!(found, amounts);
+    }
+
+    fn check_bounties_by_proposer(&mut self, bounties: &[(u64, x_bounty_proxy::BountyStatus)]) {
+        let found = self
+            .world
+            .query()
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .get_bounties_by_proposer(PROPOSER_ADDRESS)
+            .returns(ReturnsResult)
+            .run();
+
+        let mut found: Vec<(u64, x_bounty_proxy::BountyStatus)> = found
+            .into_iter()
+            .map(|bounty| (bounty.id, bounty.status))
+            .collect();
+        found.sort_by_key(|(bounty_id, _)| *bounty_id);
+        assert_eq!(found, bounties);
+    }
+
+    fn check_bounties_by_solver(
+        &mut self,
+        solver: TestAddress,
+        bounties: &[(u64, x_bounty_proxy::BountyStatus)],
+    ) {
+        let found = self
+            .world
+            .query()
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .get_bounties_by_solver(solver)
+            .returns(ReturnsResult)
+            .run();
+
+        let mut found: Vec<(u64, x_bounty_proxy::BountyStatus)> = found
+            .into_iter()
+            .map(|bounty| (bounty.id, bounty.status))
+            .collect();
+        found.sort_by_key(|(bounty_id, _)| *bounty_id);
+        assert_eq!(found, bounties);
+    }
+
+    fn check_proposer_escrow(&mut self, amounts: &[u64]) {
+        let escrow = self
+            .world
+            .query()
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .get_proposer_escrow(PROPOSER_ADDRESS)
+            .returns(ReturnsResult)
+            .run();
+
+        let found: Vec<u64> = escrow
+            .iter()
+            .map(|total| total.amount.to_u64().unwrap())
+            .collect();
+        assert_eq!(found, amounts);
+    }
+
+    fn check_solver_earnings(&mut self, solver: TestAddress, amounts: &[u64]) {
+        let earnings = self
+            .world
+            .query()
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .get_solver_earnings(solver)
+            .returns(ReturnsResult)
+            .run();
+
+        let found: Vec<u64> = earnings
+            .iter()
+            .map(|total| total.amount.to_u64().unwrap())
+            .collect();
+        assert_eq!(found, amounts);
+    }
+
+    fn check_solver_stats(&mut self, solver: TestAddress, completed: u64, uncompleted: u64) {
+        let stats = self
+            .world
+            .query()
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .get_solver_stats(solver)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(stats.completed_bounties, completed);
+        assert_eq!(stats.uncompleted_registrations, uncompleted);
+    }
+
+    fn issue_badge_collection(&mut self) {
+        self.world.set_egld_balance(OWNER_ADDRESS, BADGE_ISSUE_COST);
+        // The VM only routes calls to the system SC when its account exists
+        self.world
+            .account(ESDTSystemSCAddress)
+            .nonce(1)
+            .code(CODE_PATH);
+        self.world
+            .set_state_step(SetStateStep::new().new_token_identifier(BADGE_TOKEN_ID.as_str()));
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .issue_badge_collection("Bounty Badge", "BADGE")
+            .egld(BADGE_ISSUE_COST)
+            .run();
+    }
+
+    fn set_badge_transfer_role(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .set_badge_transfer_role()
+            .run();
+    }
+
+    fn check_no_badge(&mut self, badge_nonce: u64) {
+        let bounty = self
+            .world
+            .query()
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .get_badge_bounty(badge_nonce)
+            .returns(ReturnsResult)
+            .run();
+
+        assert!(bounty.is_none());
+    }
+
+    fn check_badge_bounty(&mut self, badge_nonce: u64, bounty_id: u64) {
+        let bounty = self
+            .world
+            .query()
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .get_badge_bounty(badge_nonce)
+            .returns(ReturnsResult)
+            .run();
+
+        assert_eq!(bounty.map(|bounty| bounty.id), Some(bounty_id));
+    }
+
+    fn open_dispute(&mut self, from: TestAddress) {
+        self.world
+            .tx()
+            .from(from)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .open_dispute(REPO_OWNER, REPO_URL, ISSUE_ID, "reason")
+            .run();
+    }
+
+    fn open_dispute_expect_err(&mut self, from: TestAddress, err_message: &str) {
+        self.world
+            .tx()
+            .from(from)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .open_dispute(REPO_OWNER, REPO_URL, ISSUE_ID, "reason")
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn expire_dispute(&mut self) {
+        self.world
+            .tx()
+            .from(ORACLE_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .expire_dispute(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .run();
+    }
+
+    fn expire_dispute_expect_err(&mut self, err_message: &str) {
+        self.world
+            .tx()
+            .from(ORACLE_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .expire_dispute(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn submit_completion(&mut self) {
+        self.world
+            .tx()
+            .from(SOLVER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .submit_completion(REPO_OWNER, REPO_URL, ISSUE_ID, "pull/42")
+            .run();
+    }
+
+    fn submit_completion_expect_err(&mut self, err_message: &str) {
+        self.world
+            .tx()
+            .from(SOLVER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .submit_completion(REPO_OWNER, REPO_URL, ISSUE_ID, "pull/42")
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn object_completion(&mut self) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .object_completion(REPO_OWNER, REPO_URL, ISSUE_ID, "reason")
+            .run();
+    }
+
+    fn check_no_completion_claim(&mut self) {
+        let claim = self
+            .world
+            .query()
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .get_completion_claim(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .returns(ReturnsResult)
+            .run();
+
+        assert!(claim.is_none());
+    }
+
+    fn finalize(&mut self) {
+        self.world
+            .tx()
+            .from(ORACLE_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .finalize(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .run();
+    }
+
+    fn finalize_expect_err(&mut self, err_message: &str) {
+        self.world
+            .tx()
+            .from(ORACLE_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .finalize(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn add_arbiter(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .add_arbiter(ARBITER_ADDRESS)
+            .run();
+    }
+
+    fn remove_arbiter(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .remove_arbiter(ARBITER_ADDRESS)
+            .run();
+    }
+
+    fn resolve_dispute(&mut self, solver_share_bps: u64) {
+        self.world
+            .tx()
+            .from(ARBITER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .resolve_dispute(
+                REPO_OWNER,
+                REPO_URL,
+                ISSUE_ID,
+                SOLVER_ADDRESS,
+                solver_share_bps,
+            )
+            .run();
+    }
+
+    fn resolve_dispute_expect_err(&mut self, err_message: &str) {
+        self.world
+            .tx()
+            .from(ARBITER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .resolve_dispute(REPO_OWNER, REPO_URL, ISSUE_ID, SOLVER_ADDRESS, 0u64)
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn create_treasury(&mut self, admin: TestAddress) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .create_treasury(REPO_OWNER, REPO_URL, admin)
+            .run();
+    }
+
+    fn create_treasury_expect_err(&mut self, admin: TestAddress, err_message: &str) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .create_treasury(REPO_OWNER, REPO_URL, admin)
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn transfer_treasury_admin(&mut self, from: TestAddress, new_admin: TestAddress) {
+        self.world
+            .tx()
+            .from(from)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .transfer_treasury_admin(REPO_OWNER, REPO_URL, new_admin)
+            .run();
+    }
+
+    fn transfer_treasury_admin_expect_err(
+        &mut self,
+        from: TestAddress,
+        new_admin: TestAddress,
+        err_message: &str,
+    ) {
+        self.world
+            .tx()
+            .from(from)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .transfer_treasury_admin(REPO_OWNER, REPO_URL, new_admin)
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn deposit_to_treasury(&mut self, amount: u64) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .deposit_to_treasury(REPO_OWNER, REPO_URL)
+            .egld(amount)
+            .run();
+    }
+
+    fn withdraw_from_treasury(&mut self, amount: u64) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .withdraw_from_treasury(
+                REPO_OWNER,
+                REPO_URL,
+                EgldOrEsdtTokenIdentifier::egld(),
+                amount,
+            )
+            .run();
+    }
+
+    fn deposit_to_treasury_expect_err(&mut self, amount: u64, err_message: &str) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .deposit_to_treasury(REPO_OWNER, REPO_URL)
+            .egld(amount)
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn withdraw_from_treasury_from(&mut self, admin: TestAddress, amount: u64) {
+        self.world
+            .tx()
+            .from(admin)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .withdraw_from_treasury(
+                REPO_OWNER,
+                REPO_URL,
+                EgldOrEsdtTokenIdentifier::egld(),
+                amount,
+            )
+            .run();
+    }
+
+    fn withdraw_from_treasury_expect_err(
+        &mut self,
+        admin: TestAddress,
+        amount: u64,
+        err_message: &str,
+    ) {
+        self.world
+            .tx()
+            .from(admin)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .withdraw_from_treasury(
+                REPO_OWNER,
+                REPO_URL,
+                EgldOrEsdtTokenIdentifier::egld(),
+                amount,
+            )
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn add_treasury_maintainer(&mut self, maintainer: TestAddress) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .add_treasury_maintainer(REPO_OWNER, REPO_URL, maintainer)
+            .run();
+    }
+
+    fn remove_treasury_maintainer(&mut self, maintainer: TestAddress) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .remove_treasury_maintainer(REPO_OWNER, REPO_URL, maintainer)
+            .run();
+    }
+
+    fn allocate_bounty(&mut self, maintainer: TestAddress, amount: u64, lockup: u64) {
+        self.world
+            .tx()
+            .from(maintainer)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .allocate_bounty(
+                REPO_OWNER,
+                REPO_URL,
+                ISSUE_ID,
+                EgldOrEsdtTokenIdentifier::egld(),
+                amount,
+                OptionalValue::Some(0u64),
+                OptionalValue::Some(lockup),
+            )
+            .run();
+    }
+
+    fn allocate_bounty_expect_err(
+        &mut self,
+        maintainer: TestAddress,
+        amount: u64,
+        err_message: &str,
+    ) {
+        self.world
+            .tx()
+            .from(maintainer)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .allocate_bounty(
+                REPO_OWNER,
+                REPO_URL,
+                ISSUE_ID,
+                EgldOrEsdtTokenIdentifier::egld(),
+                amount,
+                OptionalValue::<u64>::None,
+                OptionalValue::<u64>::None,
+            )
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn cancel_bounty_from(&mut self, proposer: TestAddress) {
+        self.world
+            .tx()
+            .from(proposer)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .cancel_bounty(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .run();
+    }
+
+    fn withdraw_registration(&mut self) {
+        self.world
+            .tx()
+            .from(SOLVER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .withdraw_registration(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .run();
+    }
+
+    fn set_registration_stake(&mut self, registration_stake: u64) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .set_registration_stake(REPO_OWNER, REPO_URL, ISSUE_ID, registration_stake)
+            .run();
+    }
+
+    fn register_with_stake(&mut self, solver: TestAddress, stake: u64) {
+        self.world.set_egld_balance(solver, stake);
+        self.world
+            .tx()
+            .from(solver)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .register(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .egld(stake)
+            .run();
+    }
+
+    fn reject_solver_as_spam(&mut self, solver: TestAddress) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .reject_solver(
+                REPO_OWNER,
+                REPO_URL,
+                ISSUE_ID,
+                solver,
+                true,
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            )
+            .run();
+    }
+
+    fn reject_solver_expect_err(&mut self, solver: TestAddress, is_spam: bool, err_message: &str) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .reject_solver(
+                REPO_OWNER,
+                REPO_URL,
+                ISSUE_ID,
+                solver,
+                is_spam,
+                OptionalValue::<ManagedBuffer<StaticApi>>::None,
+            )
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn reject_solver(&mut self, solver: TestAddress) {
+        self.world
+            .tx()
+            .from(PROPOSER_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .reject_solver(
+                REPO_OWNER,
+                REPO_URL,
+                ISSUE_ID,
+                solver,
+                false,
+                OptionalValue::Some(ManagedBuffer::from("inactive")),
+            )
+            .run();
+    }
+
+    fn expire_registrations(&mut self) {
+        self.world
+            .tx()
+            .from(ORACLE_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .expire_registrations(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .run();
+    }
+
+    fn expire_registrations_expect_err(&mut self, err_message: &str) {
+        self.world
+            .tx()
+            .from(ORACLE_ADDRESS)
+            .to(X_BOUNTY_ADDRESS)
+            .typed(x_bounty_proxy::XBountyProxy)
+            .expire_registrations(REPO_OWNER, REPO_URL, ISSUE_ID)
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn set_block_timestamp(&mut self, timestamp: u64) {
+        self.world.current_block().block_timestamp(timestamp);
+    }
+
+    fn skip_cancel_delay(&mut self) {
+        self.world
+            .current_block()
+            .block_timestamp(DEFAULT_LOCKUP + 1);
+    }
+}
+
+#[test]
+fn cancel_refunds_proposer_once() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.skip_cancel_delay();
+    state.cancel_bounty();
+
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE);
+    state.world.check_account(X_BOUNTY_ADDRESS).balance(0);
+
+    state.cancel_bounty_expect_err("Bounty cannot be cancelled");
+
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE);
+}
+
+#[test]
+fn cancel_refunds_each_backer_their_share() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.set_max_backers(2);
+
+    state.top_up_expect_err(
+        ORACLE_ADDRESS,
+        9,
+        "Top-up is below the minimum contribution",
+    );
+    state.top_up(ORACLE_ADDRESS, 500);
+    state.top_up_expect_err(ARBITER_ADDRESS, 500, "Maximum number of backers reached");
+
+    // Existing backers can still add to their contribution
+    state.top_up(ORACLE_ADDRESS, 100);
+    state.cancel_bounty();
+
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE);
+    state.world.check_account(ORACLE_ADDRESS).balance(600);
+    state.world.check_account(ARBITER_ADDRESS).balance(500);
+    state.world.check_account(X_BOUNTY_ADDRESS).balance(0);
+}
+
+#[test]
+fn cancel_after_release_fails() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.release_bounty();
+
+    state
+        .world
+        .check_account(SOLVER_ADDRESS)
+        .balance(BOUNTY_AMOUNT);
+
+    state.skip_cancel_delay();
+    state.cancel_bounty_expect_err("Bounty cannot be cancelled");
+
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE - BOUNTY_AMOUNT);
+}
+
+#[test]
+fn esdt_bounty_pays_out_in_funding_token() {
+    let mut state = XBountyTestState::new();
+
+    state.fund_bounty(
+        REPO_URL,
+        ISSUE_ID,
+        Some(BOUNTY_TOKEN_ID),
+        BOUNTY_AMOUNT,
+        0,
+        None,
+    );
+    state.cancel_bounty();
+
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE)
+        .esdt_balance(BOUNTY_TOKEN_ID, INITIAL_BALANCE);
+
+    // A cancelled issue can be funded again
+    state.fund_bounty(
+        REPO_URL,
+        ISSUE_ID,
+        Some(BOUNTY_TOKEN_ID),
+        BOUNTY_AMOUNT,
+        0,
+        None,
+    );
+    state.register();
+    state.release_bounty();
+
+    state
+        .world
+        .check_account(SOLVER_ADDRESS)
+        .balance(0)
+        .esdt_balance(BOUNTY_TOKEN_ID, BOUNTY_AMOUNT);
+    state
+        .world
+        .check_account(X_BOUNTY_ADDRESS)
+        .esdt_balance(BOUNTY_TOKEN_ID, 0);
+}
+
+#[test]
+fn oracle_releases_bounty() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+
+    state.release_by_oracle_expect_err(ORACLE_ADDRESS, "Only oracles can release the bounty");
+
+    state.add_oracle();
+    state.release_by_oracle();
+
+    state
+        .world
+        .check_account(SOLVER_ADDRESS)
+        .balance(BOUNTY_AMOUNT);
+    state.world.check_account(X_BOUNTY_ADDRESS).balance(0);
+}
+
+#[test]
+fn signed_attestation_releases_bounty() {
+    let mut state = XBountyTestState::new();
+    let other_deployment = TestSCAddress::new("other-x-bounty");
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.set_block_timestamp(100);
+
+    let attestation = state.merge_attestation(1, 99);
+    let signature = state.sign_attestation(&attestation, X_BOUNTY_ADDRESS, &SIGNER_SECRET_KEY);
+    state.claim_with_attestation_expect_err(attestation, signature, 4, "Attestation has expired");
+
+    let attestation = state.merge_attestation(1, 100);
+    let signature = state.sign_attestation(&attestation, X_BOUNTY_ADDRESS, &[9u8; 32]);
+    state.claim_with_attestation_expect_err(attestation, signature, 10, "invalid signature");
+
+    // Signatures made for another deployment do not verify here
+    let attestation = state.merge_attestation(1, 100);
+    let signature = state.sign_attestation(&attestation, other_deployment, &SIGNER_SECRET_KEY);
+    state.claim_with_attestation_expect_err(attestation, signature, 10, "invalid signature");
+
+    let attestation = state.merge_attestation(1, 100);
+    let signature = state.sign_attestation(&attestation, X_BOUNTY_ADDRESS, &SIGNER_SECRET_KEY);
+    state.claim_with_attestation(attestation.clone(), signature.clone());
+
+    state
+        .world
+        .check_account(SOLVER_ADDRESS)
+        .balance(BOUNTY_AMOUNT);
+
+    state.claim_with_attestation_expect_err(
+        attestation,
+        signature,
+        4,
+        "Attestation nonce was already used",
+    );
+}
+
+#[test]
+fn registration_is_unique_and_capped() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.register_expect_err(SOLVER_ADDRESS, "Solver is already registered");
+
+    state.set_max_solvers(1);
+    state.register_expect_err(SECOND_SOLVER_ADDRESS, "Maximum number of solvers reached");
+
+    // Withdrawing frees the slot and reopens the bounty
+    state.withdraw_registration();
+    state.release_bounty_expect_err(SOLVER_ADDRESS, "Bounty is not in claimed status");
+    state.register_solver(SECOND_SOLVER_ADDRESS);
+    state.release_bounty_expect_err(SOLVER_ADDRESS, "Solver is not registered");
+    state.release_split(&[(SECOND_SOLVER_ADDRESS, 10_000)]);
+
+    state
+        .world
+        .check_account(SECOND_SOLVER_ADDRESS)
+        .balance(BOUNTY_AMOUNT);
+}
+
+#[test]
+fn unlinked_login_moves_to_new_wallet() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.unlink_github(SOLVER_ADDRESS);
+    state.register_expect_err(SOLVER_ADDRESS, "Address is not linked to a GitHub account");
+
+    // The login is free to be linked from another wallet
+    state.link_github(ARBITER_ADDRESS, SOLVER_GITHUB);
+    state.register_expect_err(ARBITER_ADDRESS, "GitHub account is already registered");
+
+    // The old wallet keeps its registration but cannot be paid for the login
+    state.add_oracle();
+    state.release_by_oracle_expect_err(
+        ORACLE_ADDRESS,
+        "GitHub login does not match the linked identity",
+    );
+    state.submit_completion_expect_err("GitHub login does not match the linked identity");
+
+    state.withdraw_registration();
+    state.register_solver(ARBITER_ADDRESS);
+    state.release_split(&[(ARBITER_ADDRESS, 10_000)]);
+
+    state
+        .world
+        .check_account(ARBITER_ADDRESS)
+        .balance(BOUNTY_AMOUNT);
+}
+
+#[test]
+fn split_release_sends_dust_to_recipient() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.register_solver(SECOND_SOLVER_ADDRESS);
+    state.release_split_expect_err(
+        &[(SOLVER_ADDRESS, 5_000), (SOLVER_ADDRESS, 5_000)],
+        "Duplicate payee",
+    );
+    state.release_split(&[(SOLVER_ADDRESS, 3_333), (SECOND_SOLVER_ADDRESS, 6_667)]);
+
+    state.world.check_account(SOLVER_ADDRESS).balance(333);
+    state
+        .world
+        .check_account(SECOND_SOLVER_ADDRESS)
+        .balance(666);
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE - BOUNTY_AMOUNT + 1);
+    state.world.check_account(X_BOUNTY_ADDRESS).balance(0);
+}
+
+#[test]
+fn listing_views_page_over_bounty_ids() {
+    let mut state = XBountyTestState::new();
+
+    state.fund_issue(1, BOUNTY_AMOUNT);
+    state.fund_issue(2, BOUNTY_AMOUNT);
+    state.fund_issue(3, BOUNTY_AMOUNT);
+    state.cancel_issue(2);
+
+    state.check_all_bounties(0, 2, &[1, 2]);
+    state.check_all_bounties(1, 5, &[2, 3]);
+    state.check_all_bounties(3, 5, &[]);
+
+    // Each page scans `size` ids, so it may hold fewer matches
+    state.check_bounties_by_status(x_bounty_proxy::BountyStatus::Funded, 0, 2, &[1]);
+    state.check_bounties_by_status(x_bounty_proxy::BountyStatus::Funded, 2, 2, &[3]);
+    state.check_bounties_by_status(x_bounty_proxy::BountyStatus::Cancelled, 0, 3, &[2]);
+}
+
+#[test]
+fn repo_indexes_track_open_bounties() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.fund_issue(1, 500);
+    state.fund_bounty("mx-sdk-rs", ISSUE_ID, None, 200, 0, None);
+    state.check_open_bounties_by_repo(&[1, 2]);
+    state.check_open_bounties_by_org(&[1, 2, 3]);
+    state.check_repo_escrow(&[1_500]);
+
+    state.register();
+    state.release_bounty();
+    state.check_open_bounties_by_repo(&[2]);
+    state.check_open_bounties_by_org(&[2, 3]);
+    state.check_repo_escrow(&[500]);
+
+    state.cancel_issue(1);
+    state.check_open_bounties_by_repo(&[]);
+    state.check_open_bounties_by_org(&[3]);
+    state.check_repo_escrow(&[]);
+
+    // Funding a cancelled issue again reuses its bounty id
+    state.fund_issue(1, 300);
+    state.check_open_bounties_by_repo(&[2]);
+    state.check_open_bounties_by_org(&[2, 3]);
+    state.check_repo_escrow(&[300]);
+}
+
+#[test]
+fn address_views_follow_bounty_lifecycle() {
+    use x_bounty_proxy::BountyStatus::{Cancelled, Completed, Funded, Registered};
+
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.fund_issue(1, 500);
+    state.register();
+    state.register_solver(SECOND_SOLVER_ADDRESS);
+    state.check_bounties_by_proposer(&[(1, Registered), (2, Funded)]);
+    state.check_bounties_by_solver(SOLVER_ADDRESS, &[(1, Registered)]);
+    state.check_proposer_escrow(&[1_500]);
+
+    state.release_bounty();
+    state.check_bounties_by_solver(SOLVER_ADDRESS, &[(1, Completed)]);
+    state.check_bounties_by_solver(SECOND_SOLVER_ADDRESS, &[(1, Completed)]);
+    state.check_solver_earnings(SOLVER_ADDRESS, &[BOUNTY_AMOUNT]);
+    state.check_solver_earnings(SECOND_SOLVER_ADDRESS, &[]);
+    state.check_proposer_escrow(&[500]);
+
+    state.cancel_issue(1);
+    state.check_bounties_by_proposer(&[(1, Completed), (2, Cancelled)]);
+    state.check_proposer_escrow(&[]);
+
+    state.fund_issue(1, 300);
+    state.check_bounties_by_proposer(&[(1, Completed), (2, Funded)]);
+    state.check_proposer_escrow(&[300]);
+}
+
+#[test]
+fn cancel_refunds_unpaid_milestones() {
+    let mut state = XBountyTestState::new();
+
+    state.fund_milestones(&[400, 600]);
+    state.register();
+    state.release_milestone();
+
+    state.world.check_account(SOLVER_ADDRESS).balance(400);
+
+    state.skip_cancel_delay();
+    state.cancel_bounty();
+
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE - 400);
+    state.world.check_account(X_BOUNTY_ADDRESS).balance(0);
+
+    state.cancel_bounty_expect_err("Bounty cannot be cancelled");
+}
+
+#[test]
+fn every_milestone_payee_completes_the_bounty() {
+    let mut state = XBountyTestState::new();
+
+    state.fund_milestones(&[200, 300, 500]);
+    state.register();
+    state.register_solver(SECOND_SOLVER_ADDRESS);
+    state.release_milestone_to(SOLVER_ADDRESS);
+    state.release_milestone_to(SOLVER_ADDRESS);
+    state.check_solver_stats(SOLVER_ADDRESS, 0, 1);
+
+    state.release_milestone_to(SECOND_SOLVER_ADDRESS);
+
+    state.check_solver_stats(SOLVER_ADDRESS, 1, 0);
+    state.check_solver_stats(SECOND_SOLVER_ADDRESS, 1, 0);
+    state.check_solver_earnings(SOLVER_ADDRESS, &[500]);
+    state.check_solver_earnings(SECOND_SOLVER_ADDRESS, &[500]);
+}
+
+#[test]
+fn release_deducts_platform_fee() {
+    let mut state = XBountyTestState::new();
+
+    state.set_fee_expect_err(MAX_FEE_BPS + 1, "Fee cannot exceed 1000 basis points");
+    state.set_fee(500);
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.release_bounty();
+
+    state.world.check_account(SOLVER_ADDRESS).balance(950);
+    state.world.check_account(X_BOUNTY_ADDRESS).balance(50);
+
+    state.withdraw_fees();
+
+    state.world.check_account(OWNER_ADDRESS).balance(50);
+    state.world.check_account(X_BOUNTY_ADDRESS).balance(0);
+}
+
+#[test]
+fn pause_blocks_funding_but_not_cancel() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.pause();
+
+    state.fund_bounty(
+        REPO_URL,
+        ISSUE_ID,
+        None,
+        BOUNTY_AMOUNT,
+        0,
+        Some("Contract is paused"),
+    );
+
+    state.skip_cancel_delay();
+    state.cancel_bounty();
+
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE);
+}
+
+#[test]
+fn release_mints_completion_badge() {
+    let mut state = XBountyTestState::new();
+
+    state.issue_badge_collection();
+
+    // Badges are not minted while they could still be transferred
+    state.fund_issue(1, BOUNTY_AMOUNT);
+    state.register_on_issue(1);
+    state.release_issue(1);
+    state.check_no_badge(1);
+
+    state.set_badge_transfer_role();
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.release_bounty();
+
+    // Top-encoded BadgeAttributes of the completed bounty
+    let attributes = (
+        ManagedBuffer::<StaticApi>::from(REPO_OWNER),
+        ManagedBuffer::<StaticApi>::from(REPO_URL),
+        ISSUE_ID,
+        EgldOrEsdtTokenIdentifier::<StaticApi>::egld(),
+        BigUint::<StaticApi>::from(BOUNTY_AMOUNT),
+        0u64,
+    );
+    state
+        .world
+        .check_account(SOLVER_ADDRESS)
+        .balance(2 * BOUNTY_AMOUNT)
+        .esdt_nft_balance_and_attributes(BADGE_TOKEN_ID, 1, 1, attributes);
+
+    state.check_badge_bounty(1, 2);
+}
+
+#[test]
+fn stale_registration_expires_after_deadline() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+
+    state.set_block_timestamp(DEFAULT_WORK_PERIOD);
+    state.register_solver(SECOND_SOLVER_ADDRESS);
+    state.expire_registrations_expect_err("No registration has expired yet");
+
+    // Only the first solver is past its deadline
+    state.set_block_timestamp(DEFAULT_WORK_PERIOD + 1);
+    state.expire_registrations();
+    state.release_bounty_expect_err(SOLVER_ADDRESS, "Solver is not registered");
+
+    state.set_block_timestamp(2 * DEFAULT_WORK_PERIOD + 1);
+    state.expire_registrations();
+    state.expire_registrations_expect_err("Bounty is not in claimed status");
+
+    // The bounty is open again for new registrations
+    state.register();
+    state.release_bounty();
+
+    state
+        .world
+        .check_account(SOLVER_ADDRESS)
+        .balance(BOUNTY_AMOUNT);
+}
+
+#[test]
+fn cancel_respects_chosen_lockup() {
+    let mut state = XBountyTestState::new();
+    let lockup = 60 * 60 * 24 * 7;
+
+    state.fund_bounty(
+        REPO_URL,
+        ISSUE_ID,
+        None,
+        BOUNTY_AMOUNT,
+        60,
+        Some("Lockup is outside the allowed bounds"),
+    );
+    state.fund_bounty(REPO_URL, ISSUE_ID, None, BOUNTY_AMOUNT, lockup, None);
+    state.register();
+    state.withdraw_registration();
+
+    state.set_block_timestamp(lockup);
+    state.cancel_bounty_expect_err("Cancel may be performed after the lockup period");
+
+    state.set_block_timestamp(lockup + 1);
+    state.cancel_bounty();
+
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE);
+}
+
+#[test]
+fn approver_bounty_respects_chosen_lockup() {
+    let mut state = XBountyTestState::new();
+    let lockup = 60 * 60 * 24 * 7;
+
+    state.fund_with_approvers(BOUNTY_AMOUNT, 2, lockup);
+    state.register();
+    state.withdraw_registration();
+
+    state.set_block_timestamp(lockup);
+    state.cancel_bounty_expect_err("Cancel may be performed after the lockup period");
+
+    state.set_block_timestamp(lockup + 1);
+    state.cancel_bounty();
+
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE);
+}
+
+#[test]
+fn cancel_without_registrations_skips_lockup() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.cancel_bounty();
+
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE);
+}
+
+#[test]
+fn arbiter_splits_disputed_bounty() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.add_arbiter();
+    state.open_dispute(SOLVER_ADDRESS);
+
+    // Release and cancel are frozen while the dispute is open
+    state.release_bounty_expect_err(SOLVER_ADDRESS, "Bounty is not in claimed status");
+    state.skip_cancel_delay();
+    state.cancel_bounty_expect_err("Bounty cannot be cancelled");
+
+    state.remove_arbiter();
+    state.resolve_dispute_expect_err("Only arbiters can resolve disputes");
+    state.add_arbiter();
+    state.resolve_dispute(6_000);
+
+    state.world.check_account(SOLVER_ADDRESS).balance(600);
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE - 600);
+    state.world.check_account(X_BOUNTY_ADDRESS).balance(0);
+
+    state.resolve_dispute_expect_err("Bounty is not disputed");
+}
+
+#[test]
+fn unresolved_dispute_times_out() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.open_dispute_expect_err(SOLVER_ADDRESS, "No arbiters are appointed");
+
+    state.add_arbiter();
+    state.open_dispute(SOLVER_ADDRESS);
+    state.remove_arbiter();
+
+    state.set_block_timestamp(DEFAULT_DISPUTE_TIMEOUT);
+    state.expire_dispute_expect_err("Dispute has not timed out yet");
+    state.set_block_timestamp(DEFAULT_DISPUTE_TIMEOUT + 1);
+    state.expire_dispute();
+
+    // The bounty cannot be frozen again and cancels once the lockup ends
+    state.add_arbiter();
+    state.open_dispute_expect_err(SOLVER_ADDRESS, "Bounty was already disputed");
+    state.set_block_timestamp(DEFAULT_DISPUTE_TIMEOUT + DEFAULT_LOCKUP);
+    state.cancel_bounty();
+
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE);
+}
+
+#[test]
+fn timed_out_objection_cannot_be_resubmitted() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.add_arbiter();
+    state.submit_completion();
+    state.object_completion();
+
+    state.set_block_timestamp(DEFAULT_DISPUTE_TIMEOUT + 1);
+    state.expire_dispute();
+
+    // A fresh claim would need another dispute to object to it
+    state.submit_completion_expect_err("Bounty was already disputed");
+    state.set_block_timestamp(DEFAULT_DISPUTE_TIMEOUT + DEFAULT_LOCKUP);
+    state.cancel_bounty();
+
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE);
+}
+
+#[test]
+fn release_waits_for_approval_threshold() {
+    let mut state = XBountyTestState::new();
+
+    state.fund_with_approvers(BOUNTY_AMOUNT, 2, 0);
+    state.register();
+
+    state.release_bounty_expect_err(SOLVER_ADDRESS, "Only approvers can release the bounty");
+
+    state.release_bounty_from(OWNER_ADDRESS);
+    state.revoke_approval(OWNER_ADDRESS);
+    state.release_bounty_from(ORACLE_ADDRESS);
+    state.world.check_account(SOLVER_ADDRESS).balance(0);
+
+    state.release_bounty_from(ARBITER_ADDRESS);
+    state
+        .world
+        .check_account(SOLVER_ADDRESS)
+        .balance(BOUNTY_AMOUNT);
+    state.world.check_account(X_BOUNTY_ADDRESS).balance(0);
+}
+
+#[test]
+fn approver_bounties_skip_other_release_paths() {
+    let mut state = XBountyTestState::new();
+
+    state.fund_with_approvers(BOUNTY_AMOUNT, 2, 0);
+    state.register();
+    state.add_oracle();
+
+    state.release_by_oracle_expect_err(ORACLE_ADDRESS, "Bounty requires approvals to release");
+
+    let attestation = state.merge_attestation(1, 100);
+    let signature = state.sign_attestation(&attestation, X_BOUNTY_ADDRESS, &SIGNER_SECRET_KEY);
+    state.claim_with_attestation_expect_err(
+        attestation,
+        signature,
+        4,
+        "Bounty requires approvals to release",
+    );
+    state.submit_completion_expect_err("Bounty requires approvals to release");
+    state.world.check_account(SOLVER_ADDRESS).balance(0);
+}
+
+#[test]
+fn cancelled_allocation_returns_to_treasury() {
+    let mut state = XBountyTestState::new();
+
+    state.create_treasury(PROPOSER_ADDRESS);
+    state.deposit_to_treasury(2 * BOUNTY_AMOUNT);
+    state.allocate_bounty_expect_err(
+        ORACLE_ADDRESS,
+        BOUNTY_AMOUNT,
+        "Only treasury maintainers can allocate bounties",
+    );
+
+    state.add_treasury_maintainer(ORACLE_ADDRESS);
+    state.allocate_bounty_expect_err(
+        ORACLE_ADDRESS,
+        3 * BOUNTY_AMOUNT,
+        "Insufficient treasury balance",
+    );
+    state.allocate_bounty(ORACLE_ADDRESS, BOUNTY_AMOUNT, 0);
+    state.cancel_bounty_from(ORACLE_ADDRESS);
+
+    state.world.check_account(ORACLE_ADDRESS).balance(0);
+    state
+        .world
+        .check_account(X_BOUNTY_ADDRESS)
+        .balance(2 * BOUNTY_AMOUNT);
+
+    // The whole budget is back in the pool
+    state.withdraw_from_treasury(2 * BOUNTY_AMOUNT);
+
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE);
+    state.world.check_account(X_BOUNTY_ADDRESS).balance(0);
+}
+
+#[test]
+fn removed_maintainer_loses_allocated_bounties() {
+    let mut state = XBountyTestState::new();
+
+    state.create_treasury(PROPOSER_ADDRESS);
+    state.deposit_to_treasury(BOUNTY_AMOUNT);
+    state.add_treasury_maintainer(ORACLE_ADDRESS);
+    state.allocate_bounty(ORACLE_ADDRESS, BOUNTY_AMOUNT, 0);
+    state.add_delegate_from(ORACLE_ADDRESS, ARBITER_ADDRESS);
+    state.register();
+
+    state.remove_treasury_maintainer(ORACLE_ADDRESS);
+    state.release_bounty_from_expect_err(
+        ORACLE_ADDRESS,
+        "Only proposer or delegates can release the bounty",
+    );
+    state.release_bounty_from_expect_err(
+        ARBITER_ADDRESS,
+        "Only proposer or delegates can release the bounty",
+    );
+
+    // The treasury admin takes over the allocation
+    state.release_bounty_from(PROPOSER_ADDRESS);
+    state
+        .world
+        .check_account(SOLVER_ADDRESS)
+        .balance(BOUNTY_AMOUNT);
+}
+
+#[test]
+fn allocated_bounty_respects_chosen_lockup() {
+    let mut state = XBountyTestState::new();
+    let lockup = 60 * 60 * 24 * 7;
+
+    state.create_treasury(PROPOSER_ADDRESS);
+    state.deposit_to_treasury(BOUNTY_AMOUNT);
+    state.allocate_bounty(PROPOSER_ADDRESS, BOUNTY_AMOUNT, lockup);
+    state.register();
+    state.withdraw_registration();
+
+    state.set_block_timestamp(lockup);
+    state.cancel_bounty_expect_err("Cancel may be performed after the lockup period");
+
+    state.set_block_timestamp(lockup + 1);
+    state.cancel_bounty();
+    state.withdraw_from_treasury(BOUNTY_AMOUNT);
+
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE);
+}
+
+#[test]
+fn treasury_admin_is_appointed_and_transferred() {
+    let mut state = XBountyTestState::new();
+
+    // Depositing no longer opens a treasury implicitly
+    state.deposit_to_treasury_expect_err(BOUNTY_AMOUNT, "Treasury does not exist");
+
+    state.create_treasury(PROPOSER_ADDRESS);
+    state.create_treasury_expect_err(ORACLE_ADDRESS, "Treasury already exists");
+    state.deposit_to_treasury(BOUNTY_AMOUNT);
+
+    state.transfer_treasury_admin_expect_err(
+        ORACLE_ADDRESS,
+        ORACLE_ADDRESS,
+        "Only the treasury admin can manage the treasury",
+    );
+    state.transfer_treasury_admin(PROPOSER_ADDRESS, ORACLE_ADDRESS);
+    state.withdraw_from_treasury_expect_err(
+        PROPOSER_ADDRESS,
+        BOUNTY_AMOUNT,
+        "Only the treasury admin can manage the treasury",
+    );
+
+    // The contract owner can reassign the treasury as well
+    state.transfer_treasury_admin(OWNER_ADDRESS, ARBITER_ADDRESS);
+    state.withdraw_from_treasury_from(ARBITER_ADDRESS, BOUNTY_AMOUNT);
+
+    state
+        .world
+        .check_account(ARBITER_ADDRESS)
+        .balance(BOUNTY_AMOUNT);
+    state.world.check_account(X_BOUNTY_ADDRESS).balance(0);
+}
+
+#[test]
+fn delegates_release_on_behalf_of_proposer() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+
+    state.add_delegate(ORACLE_ADDRESS);
+    state.remove_delegate(ORACLE_ADDRESS);
+    state.check_delegates(&[]);
+    state.release_bounty_from_expect_err(
+        ORACLE_ADDRESS,
+        "Only proposer or delegates can release the bounty",
+    );
+
+    // Repo-wide delegation covers every bounty of the proposer in the repo
+    state.add_repo_delegate(ORACLE_ADDRESS);
+    state.check_delegates(&[ORACLE_ADDRESS]);
+    state.release_bounty_from(ORACLE_ADDRESS);
+
+    state
+        .world
+        .check_account(SOLVER_ADDRESS)
+        .balance(BOUNTY_AMOUNT);
+}
+
+#[test]
+fn rejecting_last_solver_reopens_bounty() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register_solver(SECOND_SOLVER_ADDRESS);
+
+    state.reject_solver(SECOND_SOLVER_ADDRESS);
+    state.expire_registrations_expect_err("Bounty is not in claimed status");
+
+    // A rejected solver cannot sign up for the same bounty again
+    state.register_expect_err(
+        SECOND_SOLVER_ADDRESS,
+        "Solver was rejected from this bounty",
+    );
+
+    state.register();
+    state.release_bounty();
+
+    state
+        .world
+        .check_account(SOLVER_ADDRESS)
+        .balance(BOUNTY_AMOUNT);
+}
+
+#[test]
+fn registration_stake_is_slashed_for_spam() {
+    let mut state = XBountyTestState::new();
+    let stake = 100;
+
+    state.fund(BOUNTY_AMOUNT);
+    state.set_registration_stake(stake);
+    state.register_with_stake(SOLVER_ADDRESS, stake);
+    state.register_with_stake(SECOND_SOLVER_ADDRESS, stake);
+
+    state.reject_solver_as_spam(SECOND_SOLVER_ADDRESS);
+    state.release_bounty_expect_err(SECOND_SOLVER_ADDRESS, "Solver is not registered");
+    state
+        .world
+        .check_account(PROPOSER_ADDRESS)
+        .balance(INITIAL_BALANCE - BOUNTY_AMOUNT + stake);
+
+    // The winning solver gets the stake back with the reward
+    state.release_bounty();
+    state
+        .world
+        .check_account(SOLVER_ADDRESS)
+        .balance(BOUNTY_AMOUNT + stake);
+    state.world.check_account(SECOND_SOLVER_ADDRESS).balance(0);
+    state.world.check_account(X_BOUNTY_ADDRESS).balance(0);
+}
+
+#[test]
+fn unopposed_completion_claim_finalizes_after_review() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.submit_completion();
+
+    state.finalize_expect_err("Review window has not ended yet");
+    state.set_block_timestamp(DEFAULT_REVIEW_PERIOD + 1);
+    state.finalize();
+    state.check_no_completion_claim();
+
+    state
+        .world
+        .check_account(SOLVER_ADDRESS)
+        .balance(BOUNTY_AMOUNT);
+    state.world.check_account(X_BOUNTY_ADDRESS).balance(0);
+}
+
+#[test]
+fn withdrawn_completion_claim_cannot_be_finalized() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.submit_completion();
+    state.withdraw_registration();
+
+    // Registering again after the window does not revive the old claim
+    state.set_block_timestamp(DEFAULT_REVIEW_PERIOD + 1);
+    state.register();
+    state.finalize_expect_err("No completion claim is under review");
+
+    state.world.check_account(SOLVER_ADDRESS).balance(0);
+}
+
+#[test]
+fn solver_dispute_drops_completion_claim() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.add_arbiter();
+    state.submit_completion();
+
+    // Disputing hands the claim to the arbiters instead of the review window
+    state.open_dispute(SOLVER_ADDRESS);
+    state.check_no_completion_claim();
+    state.set_block_timestamp(DEFAULT_DISPUTE_TIMEOUT + 1);
+    state.expire_dispute();
+    state.finalize_expect_err("No completion claim is under review");
+
+    state.world.check_account(SOLVER_ADDRESS).balance(0);
+}
+
+#[test]
+fn claimant_cannot_be_rejected_during_review() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.submit_completion();
+
+    state.reject_solver_expect_err(
+        SOLVER_ADDRESS,
+        false,
+        "Solver has a completion claim under review",
+    );
+    state.reject_solver_expect_err(
+        SOLVER_ADDRESS,
+        true,
+        "Solver has a completion claim under review",
+    );
+    state.cancel_bounty_expect_err("A completion claim is under review");
+}
+
+#[test]
+fn objection_disputes_completion_claim() {
+    let mut state = XBountyTestState::new();
+
+    state.fund(BOUNTY_AMOUNT);
+    state.register();
+    state.submit_completion();
+    state.add_arbiter();
+    state.object_completion();
+
+    state.set_block_timestamp(DEFAULT_REVIEW_PERIOD + 1);
+    state.finalize_expect_err("Bounty is not in claimed status");
+    state.resolve_dispute(10_000);
+
+    state
+        .world
+        .check_account(SOLVER_ADDRESS)
+        .balance(BOUNTY_AMOUNT);
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            5
-// Async Callback (empty):               1
-// Total number of exported functions:   8
+// Endpoints:                           94
+// Async Callback:                       1
+// Total number of exported functions:  97
 
 #![no_std]
 
@@ -21,11 +21,100 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         fund => fund
+        fundMilestones => fund_milestones
+        fundWithApprovers => fund_with_approvers
+        createTreasury => create_treasury
+        transferTreasuryAdmin => transfer_treasury_admin
+        depositToTreasury => deposit_to_treasury
+        withdrawFromTreasury => withdraw_from_treasury
+        addTreasuryMaintainer => add_treasury_maintainer
+        removeTreasuryMaintainer => remove_treasury_maintainer
+        allocateBounty => allocate_bounty
+        topUp => top_up
+        cancelBounty => cancel_bounty
         register => register
+        setRegistrationStake => set_registration_stake
+        linkGithub => link_github
+        unlinkGithub => unlink_github
+        withdrawRegistration => withdraw_registration
+        rejectSolver => reject_solver
+        expireRegistrations => expire_registrations
         releaseBounty => release_bounty
+        addDelegate => add_delegate
+        removeDelegate => remove_delegate
+        addRepoDelegate => add_repo_delegate
+        removeRepoDelegate => remove_repo_delegate
+        revokeApproval => revoke_approval
+        releaseMilestone => release_milestone
+        releaseSplit => release_split
+        releaseByOracle => release_by_oracle
+        claimWithAttestation => claim_with_attestation
+        openDispute => open_dispute
+        submitCompletion => submit_completion
+        objectCompletion => object_completion
+        finalize => finalize
+        expireDispute => expire_dispute
+        resolveDispute => resolve_dispute
+        addOracle => add_oracle
+        removeOracle => remove_oracle
+        addArbiter => add_arbiter
+        removeArbiter => remove_arbiter
+        setMaxSolvers => set_max_solvers
+        setMaxBackers => set_max_backers
+        setDefaultWorkPeriod => set_default_work_period
+        setReviewPeriod => set_review_period
+        setDisputeTimeout => set_dispute_timeout
+        setLockupBounds => set_lockup_bounds
+        setFee => set_fee
+        withdrawFees => withdraw_fees
+        setAttestationSigner => set_attestation_signer
+        issueBadgeCollection => issue_badge_collection
+        setBadgeTransferRole => set_badge_transfer_role
         getBounty => get_bounty
+        pause => pause
+        unpause => unpause
+        isPaused => paused
         getBountyIds => bounties
+        getBountyId => bounty_id
+        getContributions => contributions
+        getRejectedSolvers => rejected_solvers
+        getRepoDelegates => repo_delegates
+        getReleaseApprovals => release_approvals
+        getTreasury => treasury
+        getTreasuryAdmin => treasury_admin
+        getTreasuryMaintainers => treasury_maintainers
+        getMaxSolvers => max_solvers
+        getMaxBackers => max_backers
+        getDefaultWorkPeriod => default_work_period
+        getMinLockup => min_lockup
+        getMaxLockup => max_lockup
+        getSolverGithub => solvers
+        getSolverAddress => solver_address
+        getOracles => oracles
+        getArbiters => arbiters
+        getDispute => disputes
+        getDisputeTimeout => dispute_timeout
+        getReviewPeriod => review_period
+        getAttestationSigner => attestation_signer
+        getStorageVersion => storage_version
+        getFee => fee_bps
+        getCollectedFees => collected_fees
+        getBadgeToken => badge_token
+        getBountyCount => get_bounty_count
+        getAllBounties => get_all_bounties
+        getBountiesByStatus => get_bounties_by_status
+        getOpenBountiesByOrg => get_open_bounties_by_org
+        getOpenBountiesByRepo => get_open_bounties_by_repo
+        getRepoEscrow => get_repo_escrow
+        getBountiesByProposer => get_bounties_by_proposer
+        getBountiesBySolver => get_bounties_by_solver
+        getProposerEscrow => get_proposer_escrow
+        getSolverEarnings => get_solver_earnings
+        getSolverStats => get_solver_stats
+        getLeaderboard => get_leaderboard
+        getBadgeBounty => get_badge_bounty
+        getCompletionClaim => get_completion_claim
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { x_bounty }